@@ -0,0 +1,173 @@
+//! The answer to a puzzle, which is either a number or a piece of text.
+
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+use serde::Serialize;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_recognises_numbers_and_keeps_everything_else_as_text() {
+        // Given
+        let texts = ["24000", " -17\n", "CMZ", "0x1F", ""];
+
+        // When
+        let answers: Vec<_> = texts.into_iter().map(Answer::parse).collect();
+
+        // Then
+        assert_eq!(
+            answers,
+            vec![
+                Answer::Number(24000),
+                Answer::Number(-17),
+                Answer::Text("CMZ".to_owned()),
+                Answer::Text("0x1F".to_owned()),
+                Answer::Text(String::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn numbers_equal_texts_spelling_the_same_number() {
+        // Given
+        let number = Answer::from(42u64);
+
+        // When
+        let equal_to_text = number == Answer::from("042");
+
+        // Then
+        assert!(equal_to_text);
+    }
+
+    #[test]
+    fn texts_spelling_the_same_number_are_equal() {
+        // Given
+        let text = Answer::from("042");
+
+        // When
+        let equal_to_text = text == Answer::from("42");
+
+        // Then
+        assert!(equal_to_text);
+        assert_ne!(Answer::from("CMZ"), Answer::from("cmz"));
+    }
+
+    #[test]
+    fn answers_are_serialized_as_json_numbers_or_strings() {
+        // Given
+        let answers = [Answer::from(48381165u64), Answer::from("CMZ")];
+
+        // When
+        let json = serde_json::to_string(&answers).unwrap();
+
+        // Then
+        assert_eq!(json, r#"[48381165,"CMZ"]"#);
+    }
+
+    #[test]
+    fn numbers_are_ordered_numerically() {
+        // Given
+        let smaller = Answer::from(9u32);
+        let larger = Answer::from(10u32);
+
+        // When
+        let ordering = smaller.compare(&larger);
+
+        // Then
+        assert_eq!(ordering, Some(Ordering::Less));
+        assert_eq!(smaller.compare(&Answer::from("CMZ")), None);
+    }
+}
+
+/// The answer to a part of a puzzle.
+///
+/// Answers that are or spell numbers compare numerically, so that "042", "42" and 42 are all the
+/// same answer.
+#[derive(Serialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl Answer {
+    /// Reads an answer as written by [`Display`], e.g. from the cache or a puzzle description.
+    pub fn parse(text: &str) -> Self {
+        let text = text.trim();
+        match text.parse() {
+            Ok(number) => Answer::Number(number),
+            Err(_) => Answer::Text(text.to_owned()),
+        }
+    }
+
+    /// The number this answer is or spells, if any.
+    pub fn as_number(&self) -> Option<i128> {
+        match self {
+            Answer::Number(number) => Some(*number),
+            Answer::Text(text) => text.trim().parse().ok(),
+        }
+    }
+
+    /// Orders two answers numerically. Texts have no order.
+    pub fn compare(&self, other: &Answer) -> Option<Ordering> {
+        Some(self.as_number()?.cmp(&other.as_number()?))
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.as_number(), other.as_number()) {
+            (Some(number), Some(other_number)) => number == other_number,
+            (None, None) => self.to_string() == other.to_string(),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Padding, so that answers line up in tables.
+        match self {
+            Answer::Number(number) => f.pad(&number.to_string()),
+            Answer::Text(text) => f.pad(text),
+        }
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($number:ty),*) => {
+        $(
+            impl From<$number> for Answer {
+                fn from(number: $number) -> Self {
+                    Answer::Number(number.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl From<usize> for Answer {
+    fn from(number: usize) -> Self {
+        Answer::Number(number as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_owned())
+    }
+}
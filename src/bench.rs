@@ -0,0 +1,150 @@
+use std::fmt::{Display, Formatter};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use color_eyre::Result;
+
+use crate::answer::Answer;
+use crate::days::Solver;
+use crate::Part;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_returns_min_median_and_max_of_the_samples() {
+        // Given
+        let samples = [5, 1, 4, 2, 3].map(Duration::from_millis).to_vec();
+
+        // When
+        let stats = Stats::from_samples(samples);
+
+        // Then
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.max, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn stats_median_of_an_even_number_of_samples_is_the_mean_of_the_middle_two() {
+        // Given
+        let samples = [4, 1, 2, 3].map(Duration::from_millis).to_vec();
+
+        // When
+        let stats = Stats::from_samples(samples);
+
+        // Then
+        assert_eq!(stats.median, Duration::from_micros(2500));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "Cannot compute stats without samples!");
+        samples.sort_unstable();
+
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        Self {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:>10.2?}  median {:>10.2?}  max {:>10.2?}",
+            self.min, self.median, self.max
+        )
+    }
+}
+
+pub struct BenchReport {
+    pub runs: usize,
+    pub solution: Answer,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl Display for BenchReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Benchmarked {} runs, solution: {}",
+            self.runs, self.solution
+        )?;
+        writeln!(f, "Parse: {}", self.parse)?;
+        write!(f, "Solve (estimated): {}", self.solve)
+    }
+}
+
+/// The solution of a single run of a solver, with the time spent parsing and solving.
+pub struct Timing {
+    pub solution: Answer,
+    pub parse: Duration,
+    /// Estimated as the time of the complete part minus the parse time.
+    pub solve: Duration,
+}
+
+/// Runs the solver for `part` on `input` once.
+///
+/// Times the solver's parsing step on its own and then the complete part. The solve time is only
+/// an estimate: the complete part parses the input again, so its time minus the parse time is
+/// attributed to solving.
+pub fn time(solver: &dyn Solver, part: Part, input: &str) -> Result<Timing> {
+    let start = Instant::now();
+    black_box(solver.parse(black_box(input)))?;
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    let solution = black_box(part.solve(solver, black_box(input)))?;
+    let total_time = start.elapsed();
+
+    Ok(Timing {
+        solution,
+        parse: parse_time,
+        solve: total_time.saturating_sub(parse_time),
+    })
+}
+
+/// Runs the solver for `part` on `input` `runs` times, timing each run like [`time`].
+pub fn run(solver: &dyn Solver, part: Part, input: &str, runs: usize) -> Result<BenchReport> {
+    assert!(runs > 0, "Need at least one run to benchmark!");
+
+    let mut parse_samples = Vec::with_capacity(runs);
+    let mut solve_samples = Vec::with_capacity(runs);
+    let mut solution = None;
+
+    for _ in 0..runs {
+        let timing = time(solver, part, input)?;
+
+        solution = Some(timing.solution);
+        parse_samples.push(timing.parse);
+        solve_samples.push(timing.solve);
+    }
+    let solution = solution.expect("There was at least one run!");
+
+    Ok(BenchReport {
+        runs,
+        solution,
+        parse: Stats::from_samples(parse_samples),
+        solve: Stats::from_samples(solve_samples),
+    })
+}
@@ -0,0 +1,898 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fs::{
+    copy, create_dir_all, read, read_dir, read_to_string, remove_file, rename, write, File,
+};
+use std::hash::{Hash, Hasher};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use color_eyre::{
+    eyre::{eyre, Context},
+    Result,
+};
+use sha2::{Digest, Sha256};
+
+use crate::answer::Answer;
+use crate::days::Solver;
+use crate::input_fetcher::Client;
+use crate::puzzle_page;
+use crate::report::InputSource;
+use crate::submission::Rejection;
+use crate::Part;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::year_2022::day_1::Day1;
+    use crate::input_fetcher::ClientSettings;
+    use crate::test_support::{StubServer, TempDir};
+    use std::fs::create_dir;
+    use std::time::Duration;
+
+    fn stub_client(server: &StubServer) -> Client {
+        let settings = ClientSettings {
+            min_request_interval: Duration::ZERO,
+            ..Default::default()
+        };
+
+        Client::new(&server.base_url(), "abc123", settings).unwrap()
+    }
+
+    #[test]
+    fn session_key_is_the_hex_encoded_sha256_digest_of_the_session_id() {
+        // Given
+        let session_id = "abc";
+
+        // When
+        let key = session_key(session_id);
+
+        // Then
+        assert_eq!(
+            key,
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn input_day_returns_the_day_of_input_files_only() {
+        // Given
+        let file_names = [
+            "day_7.txt",
+            "day_7_part_1_answer.txt",
+            "day_x.txt",
+            "notes.txt",
+        ];
+
+        // When
+        let days: Vec<_> = file_names.into_iter().map(input_day).collect();
+
+        // Then
+        assert_eq!(days, vec![Some(7), None, None, None]);
+    }
+
+    #[test]
+    fn load_solver_input_reads_input_files() {
+        // Given
+        let temp_dir = TempDir::new();
+        let path = temp_dir.path().join("input.txt");
+        write(&path, "1000\n").unwrap();
+
+        // When
+        let input = load_solver_input(&Day1, &InputSelection::File(&path)).unwrap();
+
+        // Then
+        assert_eq!(input.text, "1000\n");
+        assert_eq!(input.source, InputSource::File);
+    }
+
+    #[test]
+    fn load_solver_input_takes_the_example_input_from_the_solver() {
+        // When
+        let input = load_solver_input(&Day1, &InputSelection::Example).unwrap();
+
+        // Then
+        assert_eq!(Some(input.text.as_str()), Day1.example_input());
+        assert_eq!(input.source, InputSource::Example);
+    }
+
+    #[test]
+    fn load_input_downloads_the_input_only_once() {
+        // Given
+        let temp_dir = TempDir::new();
+        let cache = SessionCache::in_dir(temp_dir.path().to_owned(), 2022);
+        let server = StubServer::serve(vec![StubServer::ok("1000\n")]);
+        let client = stub_client(&server);
+
+        // When
+        let downloaded = cache.load_input(1, &client).unwrap();
+        let cached = cache.load_input(1, &client).unwrap();
+
+        // Then
+        assert_eq!(
+            (downloaded.source, cached.source),
+            (InputSource::Download, InputSource::Cache)
+        );
+        assert_eq!(cached.text, "1000\n");
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn load_puzzle_downloads_the_description_again_once_part_two_is_unlocked() {
+        // Given
+        let temp_dir = TempDir::new();
+        let cache = SessionCache::in_dir(temp_dir.path().to_owned(), 2022);
+        cache
+            .write_puzzle(1, "## --- Day 1: Calorie Counting ---\n")
+            .unwrap();
+        let page = concat!(
+            r#"<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2></article>"#,
+            "<p>Your puzzle answer was <code>24000</code>.</p>",
+            r#"<article class="day-desc"><h2 id="part2">--- Part Two ---</h2></article>"#,
+        );
+        let server = StubServer::serve(vec![StubServer::ok(page)]);
+        let client = stub_client(&server);
+
+        // When
+        let stale = cache.load_puzzle(1, &client, false).unwrap();
+        cache
+            .write_answer(1, Part::One, &Answer::from(24000))
+            .unwrap();
+        let refreshed = cache.load_puzzle(1, &client, false).unwrap();
+
+        // Then
+        assert!(!stale.contains(puzzle_page::PART_TWO_HEADING));
+        assert!(refreshed.contains(puzzle_page::PART_TWO_HEADING));
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn load_puzzle_records_the_accepted_answers_of_the_page() {
+        // Given
+        let temp_dir = TempDir::new();
+        let cache = SessionCache::in_dir(temp_dir.path().to_owned(), 2022);
+        let page = concat!(
+            r#"<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2></article>"#,
+            "<p>Your puzzle answer was <code>24000</code>.</p>",
+        );
+        let server = StubServer::serve(vec![StubServer::ok(page)]);
+
+        // When
+        cache.load_puzzle(1, &stub_client(&server), true).unwrap();
+
+        // Then
+        assert_eq!(
+            cache.read_answer(1, Part::One).unwrap(),
+            Some(Answer::from(24000))
+        );
+        assert_eq!(cache.read_answer(1, Part::Two).unwrap(), None);
+    }
+
+    #[test]
+    fn is_cache_file_accepts_the_files_written_by_the_cache_only() {
+        // Given
+        let file_names = [
+            "day_7.txt",
+            "day_7.md",
+            "day_7_part_1_answer.txt",
+            "day_7_part_2_rejected.txt",
+            "day_7_part_1_notes.txt",
+            "day_x.md",
+            "notes.txt",
+        ];
+
+        // When
+        let cache_files: Vec<_> = file_names.into_iter().map(is_cache_file).collect();
+
+        // Then
+        assert_eq!(
+            cache_files,
+            vec![true, true, true, true, false, false, false]
+        );
+    }
+
+    #[test]
+    fn copy_sessions_copies_session_dirs_without_overwriting_existing_files() {
+        // Given
+        let temp_dir = TempDir::new();
+        let root = temp_dir.path();
+        let from = root.join("from");
+        let to = root.join("to");
+        let session = session_key("abc");
+        create_dir_all(from.join(&session).join("2022")).unwrap();
+        create_dir_all(to.join(&session).join("2022")).unwrap();
+        write(from.join(&session).join("2022").join("day_1.txt"), "new").unwrap();
+        write(from.join(&session).join("2022").join("day_2.txt"), "new").unwrap();
+        write(to.join(&session).join("2022").join("day_1.txt"), "old").unwrap();
+
+        // When
+        let report = copy_sessions(&from, &to).unwrap();
+
+        // Then
+        assert_eq!(report.copied_count, 1);
+        assert_eq!(
+            read(to.join(&session).join("2022").join("day_1.txt")).unwrap(),
+            b"old"
+        );
+        assert_eq!(
+            read(to.join(&session).join("2022").join("day_2.txt")).unwrap(),
+            b"new"
+        );
+    }
+
+    #[test]
+    fn copy_sessions_skips_everything_that_is_not_part_of_a_session_cache() {
+        // Given
+        let temp_dir = TempDir::new();
+        let from = temp_dir.path().join("from");
+        let to = temp_dir.path().join("to");
+        let session_dir = from.join(session_key("abc"));
+        create_dir_all(from.join("target").join("debug")).unwrap();
+        create_dir_all(session_dir.join("2022").join("drafts")).unwrap();
+        create_dir_all(session_dir.join("backup")).unwrap();
+        write(from.join("not_a_session.txt"), "ignored").unwrap();
+        write(session_dir.join("2022").join("day_1.txt"), "1000").unwrap();
+        write(session_dir.join("2022").join("notes.txt"), "ignored").unwrap();
+
+        // When
+        let mut report = copy_sessions(&from, &to).unwrap();
+
+        // Then
+        report.skipped.sort();
+        assert_eq!(report.copied_count, 1);
+        assert_eq!(
+            report.skipped,
+            vec![
+                session_dir.join("2022").join("drafts"),
+                session_dir.join("2022").join("notes.txt"),
+                session_dir.join("backup"),
+                from.join("not_a_session.txt"),
+                from.join("target"),
+            ]
+        );
+        assert!(!to.join("target").exists());
+        assert!(!to.join("not_a_session.txt").exists());
+        assert!(!to.join(session_key("abc")).join("backup").exists());
+        assert!(!to
+            .join(session_key("abc"))
+            .join("2022")
+            .join("notes.txt")
+            .exists());
+    }
+
+    #[test]
+    fn copy_sessions_keeps_the_modification_times_as_fetch_times() {
+        // Given
+        let temp_dir = TempDir::new();
+        let from = temp_dir.path().join("from");
+        let to = temp_dir.path().join("to");
+        let input_path = from.join(session_key("abc")).join("2022").join("day_1.txt");
+        create_dir_all(input_path.parent().unwrap()).unwrap();
+        write(&input_path, "1000").unwrap();
+        let fetched_at = SystemTime::UNIX_EPOCH + Duration::from_secs(1_669_870_800);
+        File::options()
+            .write(true)
+            .open(&input_path)
+            .unwrap()
+            .set_modified(fetched_at)
+            .unwrap();
+
+        // When
+        copy_sessions(&from, &to).unwrap();
+
+        // Then
+        let session = SessionCache::in_dir(to.join(session_key("abc")).join("2022"), 2022);
+        let inputs = session.inputs().unwrap();
+        assert_eq!(inputs[0].fetched_at, fetched_at);
+    }
+
+    #[test]
+    fn migrate_legacy_dir_moves_the_legacy_cache_to_the_new_location() {
+        // Given
+        let temp_dir = TempDir::new();
+        let root = temp_dir.path();
+        let legacy_dir = root.join("legacy");
+        let new_dir = root.join("new");
+        create_dir_all(&legacy_dir).unwrap();
+        write(legacy_dir.join("day_1.txt"), "1000").unwrap();
+
+        // When
+        let migration = migrate_legacy_dir(&legacy_dir, &new_dir).unwrap();
+
+        // Then
+        assert_eq!(
+            migration,
+            Some(Migration {
+                from: legacy_dir.clone(),
+                to: new_dir.clone(),
+            })
+        );
+        assert!(!legacy_dir.exists());
+        assert_eq!(read(new_dir.join("day_1.txt")).unwrap(), b"1000");
+    }
+
+    #[test]
+    fn migrate_flat_layout_moves_the_files_of_a_session_into_the_dir_of_2022() {
+        // Given
+        let temp_dir = TempDir::new();
+        let root = temp_dir.path();
+        create_dir_all(root.join("2015")).unwrap();
+        write(root.join("day_1.txt"), "1000").unwrap();
+        write(root.join("day_1_part_1_answer.txt"), "24000").unwrap();
+
+        // When
+        migrate_flat_layout(root).unwrap();
+
+        // Then
+        assert!(!root.join("day_1.txt").exists());
+        assert_eq!(read(root.join("2022").join("day_1.txt")).unwrap(), b"1000");
+        assert_eq!(
+            read(root.join("2022").join("day_1_part_1_answer.txt")).unwrap(),
+            b"24000"
+        );
+        assert!(root.join("2015").is_dir());
+    }
+
+    #[test]
+    fn migrate_legacy_dir_leaves_an_existing_new_cache_alone() {
+        // Given
+        let temp_dir = TempDir::new();
+        let root = temp_dir.path();
+        let legacy_dir = root.join("legacy");
+        let new_dir = root.join("new");
+        create_dir_all(&legacy_dir).unwrap();
+        create_dir(&new_dir).unwrap();
+
+        // When
+        let migration = migrate_legacy_dir(&legacy_dir, &new_dir).unwrap();
+
+        // Then
+        assert_eq!(migration, None);
+        assert!(legacy_dir.exists());
+    }
+}
+
+/// The cache directory of the application, shared by all sessions.
+pub fn cache_dir() -> PathBuf {
+    let package_name = env!("CARGO_PKG_NAME");
+    let project_dir =
+        directories::ProjectDirs::from("", "AndreasKargSoftware", package_name).unwrap();
+
+    project_dir.cache_dir().to_owned()
+}
+
+/// The year of all puzzles cached before caches were split up by year.
+const FLAT_LAYOUT_YEAR: u16 = 2022;
+
+/// Cached puzzle inputs and accepted answers of a single session and year.
+pub struct SessionCache {
+    dir: PathBuf,
+    year: u16,
+    migration: Option<Migration>,
+}
+
+/// A session cache that [`SessionCache::new`] moved from its legacy location.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Migration {
+    pub from: PathBuf,
+    pub to: PathBuf,
+}
+
+/// Puzzle input along with where it came from.
+pub struct LoadedInput {
+    pub text: String,
+    pub source: InputSource,
+}
+
+/// Where to load the puzzle input of a solver from.
+pub enum InputSelection<'a> {
+    /// A file, or stdin for "-".
+    File(&'a Path),
+    /// The cache of a session, downloading the input with the client if it is not cached yet.
+    Session {
+        cache: &'a SessionCache,
+        client: &'a Client,
+    },
+    /// The example input of the solver.
+    Example,
+}
+
+/// Loads the puzzle input of the solver from where `selection` points to.
+pub fn load_solver_input(solver: &dyn Solver, selection: &InputSelection) -> Result<LoadedInput> {
+    let advent_day = solver.day();
+    let input = match selection {
+        InputSelection::File(path) => LoadedInput {
+            text: read_input_file(path)?,
+            source: if *path == Path::new("-") {
+                InputSource::Stdin
+            } else {
+                InputSource::File
+            },
+        },
+        InputSelection::Session { cache, client } => cache.load_input(advent_day, client)?,
+        InputSelection::Example => LoadedInput {
+            text: solver
+                .example_input()
+                .ok_or_else(|| eyre!("Day {advent_day} has no example input!"))?
+                .to_owned(),
+            source: InputSource::Example,
+        },
+    };
+
+    Ok(input)
+}
+
+/// Reads puzzle input from `path`, or from stdin if `path` is "-".
+pub fn read_input_file(path: &Path) -> Result<String> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .wrap_err("Unable to read input from stdin!")?;
+
+        return Ok(input);
+    }
+
+    read_to_string(path).wrap_err_with(|| format!("Unable to read input from {}!", path.display()))
+}
+
+pub struct CachedInput {
+    pub advent_day: u8,
+    pub size: u64,
+    pub fetched_at: SystemTime,
+}
+
+impl SessionCache {
+    pub fn new(session_id: &str, year: u16) -> Result<Self> {
+        let cache_dir = cache_dir();
+        let session_dir = cache_dir.join(session_key(session_id));
+        let migration = migrate_legacy_dir(
+            &cache_dir.join(legacy_session_key(session_id)),
+            &session_dir,
+        )?;
+        migrate_flat_layout(&session_dir)?;
+
+        Ok(Self {
+            dir: session_dir.join(year.to_string()),
+            year,
+            migration,
+        })
+    }
+
+    /// The caches of all sessions that ever used this tool on this machine, for every year.
+    pub fn all() -> Result<Vec<Self>> {
+        let cache_dir = cache_dir();
+        if !cache_dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut caches = Vec::new();
+        for session in read_dir(&cache_dir)
+            .wrap_err_with(|| format!("Unable to list {}!", cache_dir.display()))?
+        {
+            let session_dir = session?.path();
+            if !session_dir.is_dir() {
+                continue;
+            }
+            migrate_flat_layout(&session_dir)?;
+
+            for entry in read_dir(&session_dir)
+                .wrap_err_with(|| format!("Unable to list {}!", session_dir.display()))?
+            {
+                let dir = entry?.path();
+                let year = dir.file_name().and_then(|name| name.to_str()?.parse().ok());
+                let Some(year) = year.filter(|_| dir.is_dir()) else {
+                    continue;
+                };
+
+                caches.push(Self {
+                    dir,
+                    year,
+                    migration: None,
+                });
+            }
+        }
+        caches.sort_by(|a, b| a.dir.cmp(&b.dir));
+
+        Ok(caches)
+    }
+
+    /// The cache of a session in `dir` rather than in the cache directory of the application.
+    pub fn in_dir(dir: PathBuf, year: u16) -> Self {
+        Self {
+            dir,
+            year,
+            migration: None,
+        }
+    }
+
+    /// Where the cache was moved from when it was opened, if it was still at its legacy location.
+    pub fn migration(&self) -> Option<&Migration> {
+        self.migration.as_ref()
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// All cached inputs of the session, ordered by day.
+    pub fn inputs(&self) -> Result<Vec<CachedInput>> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut inputs = Vec::new();
+        for entry in read_dir(&self.dir)
+            .wrap_err_with(|| format!("Unable to list {}!", self.dir.display()))?
+        {
+            let entry = entry?;
+            let Some(advent_day) = entry.file_name().to_str().and_then(input_day) else {
+                continue;
+            };
+
+            let metadata = entry.metadata()?;
+            inputs.push(CachedInput {
+                advent_day,
+                size: metadata.len(),
+                fetched_at: metadata.modified()?,
+            });
+        }
+        inputs.sort_by_key(|input| input.advent_day);
+
+        Ok(inputs)
+    }
+
+    /// Deletes the cached input of the day. Returns whether there was one.
+    pub fn remove_input(&self, advent_day: u8) -> Result<bool> {
+        let path = self.input_path(advent_day);
+        if !path.exists() {
+            return Ok(false);
+        }
+
+        remove_file(&path).wrap_err_with(|| format!("Unable to delete {}!", path.display()))?;
+
+        Ok(true)
+    }
+
+    /// Loads the puzzle input of the day from the cache, or downloads it with `client` if it is
+    /// not cached yet.
+    pub fn load_input(&self, advent_day: u8, client: &Client) -> Result<LoadedInput> {
+        if let Some(text) = self.read_input(advent_day)? {
+            return Ok(LoadedInput {
+                text,
+                source: InputSource::Cache,
+            });
+        }
+
+        let text = client.fetch(self.year, advent_day)?;
+        self.write_input(advent_day, &text)?;
+
+        Ok(LoadedInput {
+            text,
+            source: InputSource::Download,
+        })
+    }
+
+    /// Loads the puzzle description of the day as Markdown from the cache, or downloads it with
+    /// `client` if it is not cached yet, lacks the unlocked part two or `refresh` is set.
+    ///
+    /// The answers accepted so far are recorded whenever the puzzle page is downloaded.
+    pub fn load_puzzle(&self, advent_day: u8, client: &Client, refresh: bool) -> Result<String> {
+        if !refresh {
+            if let Some(puzzle) = self.read_puzzle(advent_day)? {
+                // Part two is unlocked once part one is solved, so a description without it is
+                // stale.
+                let part_two_unlocked = self.read_answer(advent_day, Part::One)?.is_some();
+                if puzzle.contains(puzzle_page::PART_TWO_HEADING) || !part_two_unlocked {
+                    return Ok(puzzle);
+                }
+            }
+        }
+
+        let page = client.fetch_puzzle(self.year, advent_day)?;
+        let puzzle = puzzle_page::to_markdown(&page);
+        self.write_puzzle(advent_day, &puzzle)?;
+        for (part, answer) in Part::ALL
+            .into_iter()
+            .zip(puzzle_page::accepted_answers(&page))
+        {
+            self.write_answer(advent_day, part, &answer)?;
+        }
+
+        Ok(puzzle)
+    }
+
+    pub fn input_path(&self, advent_day: u8) -> PathBuf {
+        self.dir.join(format!("day_{advent_day}.txt"))
+    }
+
+    pub fn puzzle_path(&self, advent_day: u8) -> PathBuf {
+        self.dir.join(format!("day_{advent_day}.md"))
+    }
+
+    pub fn answer_path(&self, advent_day: u8, part: Part) -> PathBuf {
+        self.dir
+            .join(format!("day_{advent_day}_part_{}_answer.txt", part.level()))
+    }
+
+    pub fn rejections_path(&self, advent_day: u8, part: Part) -> PathBuf {
+        self.dir.join(format!(
+            "day_{advent_day}_part_{}_rejected.txt",
+            part.level()
+        ))
+    }
+
+    pub fn read_input(&self, advent_day: u8) -> Result<Option<String>> {
+        read_if_exists(&self.input_path(advent_day))
+    }
+
+    pub fn write_input(&self, advent_day: u8, input: &str) -> Result<()> {
+        self.write(&self.input_path(advent_day), input)
+    }
+
+    pub fn read_puzzle(&self, advent_day: u8) -> Result<Option<String>> {
+        read_if_exists(&self.puzzle_path(advent_day))
+    }
+
+    pub fn write_puzzle(&self, advent_day: u8, puzzle: &str) -> Result<()> {
+        self.write(&self.puzzle_path(advent_day), puzzle)
+    }
+
+    pub fn read_answer(&self, advent_day: u8, part: Part) -> Result<Option<Answer>> {
+        let answer = read_if_exists(&self.answer_path(advent_day, part))?;
+
+        Ok(answer.as_deref().map(Answer::parse))
+    }
+
+    pub fn write_answer(&self, advent_day: u8, part: Part, answer: &Answer) -> Result<()> {
+        self.write(&self.answer_path(advent_day, part), &answer.to_string())
+    }
+
+    /// The answers the website rejected for the part, with the hint it gave for each.
+    pub fn read_rejections(&self, advent_day: u8, part: Part) -> Result<Vec<Rejection>> {
+        let rejections = read_if_exists(&self.rejections_path(advent_day, part))?;
+
+        Ok(rejections
+            .unwrap_or_default()
+            .lines()
+            .filter_map(Rejection::parse)
+            .collect())
+    }
+
+    pub fn add_rejection(&self, advent_day: u8, part: Part, rejection: &Rejection) -> Result<()> {
+        let path = self.rejections_path(advent_day, part);
+        let mut rejections = read_if_exists(&path)?.unwrap_or_default();
+        rejections.push_str(&format!("{}\n", rejection.to_line()));
+
+        self.write(&path, &rejections)
+    }
+
+    fn write(&self, path: &Path, contents: &str) -> Result<()> {
+        create_dir_all(&self.dir)
+            .wrap_err_with(|| format!("Unable to create dir {}!", self.dir.display()))?;
+        write(path, contents).wrap_err_with(|| format!("Unable to write to {}!", path.display()))
+    }
+}
+
+/// The outcome of [`copy_sessions`].
+#[derive(Debug, Default)]
+pub struct CopyReport {
+    /// The number of copied files.
+    pub copied_count: usize,
+    /// The entries of `from` that are not part of a session cache and were left out.
+    pub skipped: Vec<PathBuf>,
+}
+
+/// Copies the session caches found in `from` into `to`, e.g. to move them to another machine.
+///
+/// Only session dirs, their year dirs and the cache files in those are copied; anything else is
+/// reported as skipped. Files that already exist in `to` are left untouched.
+pub fn copy_sessions(from: &Path, to: &Path) -> Result<CopyReport> {
+    let mut report = CopyReport::default();
+
+    for session in read_dir(from).wrap_err_with(|| format!("Unable to list {}!", from.display()))? {
+        let session = session?;
+        let is_session_dir = session.file_type()?.is_dir()
+            && session.file_name().to_str().is_some_and(is_session_key);
+        if !is_session_dir {
+            report.skipped.push(session.path());
+            continue;
+        }
+
+        let session_dir = to.join(session.file_name());
+        for year in read_dir(session.path())
+            .wrap_err_with(|| format!("Unable to list {}!", session.path().display()))?
+        {
+            let year = year?;
+            let is_year_dir = year.file_type()?.is_dir()
+                && year
+                    .file_name()
+                    .to_str()
+                    .is_some_and(|name| name.parse::<u16>().is_ok());
+            if is_year_dir {
+                copy_missing_files(
+                    &year.path(),
+                    &session_dir.join(year.file_name()),
+                    &mut report,
+                )?;
+            } else {
+                report.skipped.push(year.path());
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// Copies the cache files of the year dir `from` that do not exist in `to` yet.
+fn copy_missing_files(from: &Path, to: &Path, report: &mut CopyReport) -> Result<()> {
+    create_dir_all(to).wrap_err_with(|| format!("Unable to create dir {}!", to.display()))?;
+
+    for entry in read_dir(from).wrap_err_with(|| format!("Unable to list {}!", from.display()))? {
+        let entry = entry?;
+        let is_cache_file =
+            entry.file_type()?.is_file() && entry.file_name().to_str().is_some_and(is_cache_file);
+        if !is_cache_file {
+            report.skipped.push(entry.path());
+            continue;
+        }
+
+        let target_path = to.join(entry.file_name());
+        if target_path.exists() {
+            continue;
+        }
+
+        copy(entry.path(), &target_path).wrap_err_with(|| {
+            format!(
+                "Unable to copy {} to {}!",
+                entry.path().display(),
+                target_path.display()
+            )
+        })?;
+        // The modification time of an input is the time it was fetched.
+        File::options()
+            .write(true)
+            .open(&target_path)
+            .and_then(|file| file.set_modified(entry.metadata()?.modified()?))
+            .wrap_err_with(|| {
+                format!(
+                    "Unable to set the modification time of {}!",
+                    target_path.display()
+                )
+            })?;
+        report.copied_count += 1;
+    }
+
+    Ok(())
+}
+
+/// Whether `name` is the name of a session dir, i.e. a [`session_key`].
+fn is_session_key(name: &str) -> bool {
+    name.len() == 64
+        && name
+            .bytes()
+            .all(|byte| matches!(byte, b'0'..=b'9' | b'a'..=b'f'))
+}
+
+/// Whether `name` is the name of an input, puzzle description, answer or rejections file.
+fn is_cache_file(name: &str) -> bool {
+    if input_day(name).is_some() {
+        return true;
+    }
+    let Some(name) = name.strip_prefix("day_") else {
+        return false;
+    };
+    if let Some(advent_day) = name.strip_suffix(".md") {
+        return advent_day.parse::<u8>().is_ok();
+    }
+
+    let Some((advent_day, name)) = name.split_once("_part_") else {
+        return false;
+    };
+    let Some((level, kind)) = name.split_once('_') else {
+        return false;
+    };
+
+    advent_day.parse::<u8>().is_ok()
+        && level.parse::<u8>().is_ok()
+        && matches!(kind, "answer.txt" | "rejected.txt")
+}
+
+/// The day of a cached input file named like `day_7.txt`.
+fn input_day(file_name: &str) -> Option<u8> {
+    file_name
+        .strip_prefix("day_")?
+        .strip_suffix(".txt")?
+        .parse()
+        .ok()
+}
+
+/// The name of the cache directory of a session: the hex-encoded SHA-256 digest of its id.
+///
+/// Unlike `DefaultHasher`, the digest is guaranteed to be the same across Rust releases, so
+/// upgrading the toolchain does not orphan the cache.
+pub fn session_key(session_id: &str) -> String {
+    let digest = Sha256::digest(session_id.as_bytes());
+
+    digest.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// The name of the cache directory of a session in earlier versions of this tool.
+///
+/// `DefaultHasher` output may change with every Rust release, so this only finds caches that
+/// were written by a build from the same toolchain.
+fn legacy_session_key(session_id: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    session_id.hash(&mut hasher);
+
+    format!("{:x}", hasher.finish())
+}
+
+/// Moves a session cache from its legacy location, unless there already is a cache at the new
+/// location.
+fn migrate_legacy_dir(legacy_dir: &Path, new_dir: &Path) -> Result<Option<Migration>> {
+    if !legacy_dir.is_dir() || new_dir.exists() {
+        return Ok(None);
+    }
+
+    rename(legacy_dir, new_dir).wrap_err_with(|| {
+        format!(
+            "Unable to move cache from {} to {}!",
+            legacy_dir.display(),
+            new_dir.display()
+        )
+    })?;
+
+    Ok(Some(Migration {
+        from: legacy_dir.to_owned(),
+        to: new_dir.to_owned(),
+    }))
+}
+
+/// Moves the files of a session cache from before caches were split up by year into the dir of
+/// their year.
+fn migrate_flat_layout(session_dir: &Path) -> Result<()> {
+    if !session_dir.is_dir() {
+        return Ok(());
+    }
+
+    let year_dir = session_dir.join(FLAT_LAYOUT_YEAR.to_string());
+    for entry in read_dir(session_dir)
+        .wrap_err_with(|| format!("Unable to list {}!", session_dir.display()))?
+    {
+        let entry = entry?;
+        if !entry.file_type()?.is_file() {
+            continue;
+        }
+
+        create_dir_all(&year_dir)
+            .wrap_err_with(|| format!("Unable to create dir {}!", year_dir.display()))?;
+        let target_path = year_dir.join(entry.file_name());
+        rename(entry.path(), &target_path).wrap_err_with(|| {
+            format!(
+                "Unable to move {} to {}!",
+                entry.path().display(),
+                target_path.display()
+            )
+        })?;
+    }
+
+    Ok(())
+}
+
+fn read_if_exists(path: &Path) -> Result<Option<String>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let contents =
+        read(path).wrap_err_with(|| format!("Unable to read cache file at {}!", path.display()))?;
+    let contents = String::from_utf8(contents)
+        .wrap_err_with(|| format!("Invalid UTF-8 in {}!", path.display()))?;
+
+    Ok(Some(contents))
+}
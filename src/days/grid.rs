@@ -0,0 +1,310 @@
+//! A rectangular grid of cells, as used by the puzzles with a map or a picture as input.
+
+use std::ops::{Index, IndexMut};
+
+use color_eyre::{
+    eyre::{bail, eyre},
+    Result,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    const DIGITS: &str = indoc! {"
+        123
+        456
+    "};
+
+    #[test]
+    fn parse_digits_reads_one_row_per_line() {
+        // Given
+        let input = DIGITS;
+
+        // When
+        let grid = Grid::parse_digits(input).unwrap();
+
+        // Then
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Position::new(1, 2)], 6);
+        assert_eq!(grid.row(0).copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+    }
+
+    #[test]
+    fn parse_rejects_rows_of_different_lengths() {
+        // Given
+        let input = "123\n45\n";
+
+        // When
+        let outcome = Grid::parse_chars(input);
+
+        // Then
+        let error = outcome.unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Row 2 has 2 cells, but the rows above have 3!"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Row 2 is out of bounds!")]
+    fn row_panics_for_rows_outside_of_the_grid() {
+        // Given
+        let grid = Grid::parse_digits(DIGITS).unwrap();
+
+        // When
+        let _ = grid.row(2);
+
+        // Then it panics
+    }
+
+    #[test]
+    fn neighbours_only_returns_positions_inside_the_grid() {
+        // Given
+        let grid = Grid::parse_digits(DIGITS).unwrap();
+
+        // When
+        let neighbours: Vec<_> = grid.neighbours(Position::new(0, 0)).collect();
+
+        // Then
+        assert_eq!(neighbours, vec![Position::new(1, 0), Position::new(0, 1)]);
+    }
+
+    #[test]
+    fn walk_returns_the_cells_up_to_the_edge_of_the_grid() {
+        // Given
+        let grid = Grid::parse_digits(DIGITS).unwrap();
+
+        // When
+        let cells: Vec<_> = grid
+            .walk(Position::new(1, 2), Direction::Left)
+            .map(|position| grid[position])
+            .collect();
+
+        // Then
+        assert_eq!(cells, vec![5, 4]);
+    }
+}
+
+/// The position of a cell, counting rows from the top and columns from the left.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position {
+    pub row: usize,
+    pub column: usize,
+}
+
+impl Position {
+    pub const fn new(row: usize, column: usize) -> Self {
+        Self { row, column }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    /// The change of row and column of a single step in this direction.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+            Direction::Right => (0, 1),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    /// The cells, row by row.
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Parses a grid with one row per line, turning each character into a cell with `parse_cell`.
+    pub fn parse(input: &str, mut parse_cell: impl FnMut(char) -> Result<T>) -> Result<Self> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for line in input.lines() {
+            let row_start = cells.len();
+            for c in line.chars() {
+                cells.push(parse_cell(c)?);
+            }
+            let row_width = cells.len() - row_start;
+
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => bail!(
+                    "Row {} has {row_width} cells, but the rows above have {width}!",
+                    height + 1
+                ),
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self {
+                width,
+                height,
+                cells,
+            }),
+            _ => bail!("The grid has no cells!"),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    /// All positions of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+
+        (0..self.height)
+            .flat_map(move |row| (0..width).map(move |column| Position::new(row, column)))
+    }
+
+    /// The position of the first cell that matches `predicate`, row by row.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.positions()
+            .find(|&position| predicate(&self[position]))
+    }
+
+    /// The cells of the given row, from left to right.
+    pub fn row(&self, row: usize) -> impl Iterator<Item = &T> {
+        assert!(row < self.height, "Row {row} is out of bounds!");
+
+        self.cells[row * self.width..(row + 1) * self.width].iter()
+    }
+
+    /// The cells of the given column, from top to bottom.
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        assert!(column < self.width, "Column {column} is out of bounds!");
+
+        self.cells.iter().skip(column).step_by(self.width)
+    }
+
+    /// The position next to `position` in the given direction, if it is inside the grid.
+    pub fn neighbour(&self, position: Position, direction: Direction) -> Option<Position> {
+        let (row_offset, column_offset) = direction.offset();
+        let row = position.row.checked_add_signed(row_offset)?;
+        let column = position.column.checked_add_signed(column_offset)?;
+
+        (row < self.height && column < self.width).then_some(Position::new(row, column))
+    }
+
+    /// The positions directly above, below, left and right of `position` inside the grid.
+    pub fn neighbours(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.neighbour(position, direction))
+    }
+
+    /// The positions from `start` (exclusive) in the given direction up to the edge of the grid.
+    pub fn walk(
+        &self,
+        start: Position,
+        direction: Direction,
+    ) -> impl Iterator<Item = Position> + '_ {
+        std::iter::successors(self.neighbour(start, direction), move |&position| {
+            self.neighbour(position, direction)
+        })
+    }
+
+    /// A grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn index_of(&self, position: Position) -> Option<usize> {
+        (position.row < self.height && position.column < self.width)
+            .then_some(position.row * self.width + position.column)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A grid of the given size with every cell set to `cell`.
+    pub fn new(width: usize, height: usize, cell: T) -> Self {
+        assert!(width > 0 && height > 0, "The grid needs at least one cell!");
+
+        Self {
+            width,
+            height,
+            cells: vec![cell; width * height],
+        }
+    }
+}
+
+impl Grid<u8> {
+    /// Parses a grid of single decimal digits.
+    pub fn parse_digits(input: &str) -> Result<Self> {
+        Self::parse(input, |c| {
+            c.to_digit(10)
+                .map(|digit| digit as u8)
+                .ok_or_else(|| eyre!("'{c}' is not a digit!"))
+        })
+    }
+}
+
+impl Grid<char> {
+    /// Parses a grid of characters.
+    pub fn parse_chars(input: &str) -> Result<Self> {
+        Self::parse(input, Ok)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        match self.index_of(position) {
+            Some(index) => &self.cells[index],
+            None => panic!("{position:?} is outside of the grid!"),
+        }
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        match self.index_of(position) {
+            Some(index) => &mut self.cells[index],
+            None => panic!("{position:?} is outside of the grid!"),
+        }
+    }
+}
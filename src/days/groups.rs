@@ -0,0 +1,103 @@
+//! Groups consecutive items of an iterator into fixed-size arrays, like the unstable
+//! `Iterator::array_chunks`.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn groups_yields_arrays_of_consecutive_items() {
+        // Given
+        let items = 1..=6;
+
+        // When
+        let groups: Vec<_> = items.groups::<3>().collect();
+
+        // Then
+        assert_eq!(groups, vec![[1, 2, 3], [4, 5, 6]]);
+    }
+
+    #[test]
+    fn groups_keeps_the_items_of_an_incomplete_last_group_as_remainder() {
+        // Given
+        let items = ["a", "b", "c", "d", "e"];
+
+        // When
+        let mut groups = items.into_iter().groups::<2>();
+        let complete_groups: Vec<_> = groups.by_ref().collect();
+
+        // Then
+        assert_eq!(complete_groups, vec![["a", "b"], ["c", "d"]]);
+        assert_eq!(groups.remainder(), ["e"]);
+    }
+
+    #[test]
+    fn groups_has_no_remainder_if_the_items_fit_exactly() {
+        // Given
+        let items = 0..4;
+
+        // When
+        let mut groups = items.groups::<4>();
+        let group_count = groups.by_ref().count();
+
+        // Then
+        assert_eq!(group_count, 1);
+        assert!(groups.remainder().is_empty());
+    }
+}
+
+pub trait Groups: Iterator + Sized {
+    /// Groups consecutive items into arrays of `N` items.
+    ///
+    /// If the number of items is not a multiple of `N`, the items of the incomplete last group
+    /// are not yielded but available from [`ArrayGroups::remainder`] afterwards.
+    fn groups<const N: usize>(self) -> ArrayGroups<Self, N> {
+        assert!(N > 0, "Groups need at least one item!");
+
+        ArrayGroups {
+            iter: self,
+            remainder: Vec::new(),
+        }
+    }
+}
+
+impl<I: Iterator> Groups for I {}
+
+/// The iterator returned by [`Groups::groups`].
+pub struct ArrayGroups<I: Iterator, const N: usize> {
+    iter: I,
+    remainder: Vec<I::Item>,
+}
+
+impl<I: Iterator, const N: usize> ArrayGroups<I, N> {
+    /// The items of the incomplete last group, once the iterator is exhausted.
+    pub fn remainder(&self) -> &[I::Item] {
+        &self.remainder
+    }
+}
+
+impl<I: Iterator, const N: usize> Iterator for ArrayGroups<I, N> {
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let group: Vec<_> = self.iter.by_ref().take(N).collect();
+
+        match group.try_into() {
+            Ok(group) => Some(group),
+            Err(incomplete_group) => {
+                // Keep the remainder when called again after the iterator is exhausted.
+                if !incomplete_group.is_empty() {
+                    self.remainder = incomplete_group;
+                }
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+
+        (lower / N, upper.map(|upper| upper / N))
+    }
+}
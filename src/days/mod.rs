@@ -1,7 +1,113 @@
-pub mod day_1;
-pub mod day_2;
-pub mod day_3;
-pub mod day_4;
-pub mod day_5;
-pub mod day_6;
-pub mod day_7;
+use color_eyre::{
+    eyre::{bail, eyre},
+    Result,
+};
+use std::fmt::Debug;
+
+use crate::answer::Answer;
+
+pub mod grid;
+pub mod groups;
+pub mod ocr;
+pub mod search;
+pub mod year_2022;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn parse_u32(i: &str) -> nom::IResult<&str, u32> {
+        nom::character::complete::u32(i)
+    }
+
+    #[test]
+    fn parse_all_ignores_trailing_whitespace() {
+        // When
+        let number = parse_all(parse_u32, "42\n").unwrap();
+
+        // Then
+        assert_eq!(number, 42);
+    }
+
+    #[test]
+    fn parse_all_fails_if_anything_else_is_left_over() {
+        // When
+        let result = parse_all(parse_u32, "42x\n");
+
+        // Then
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Unable to parse the puzzle input from \"x\n\" on!"
+        );
+    }
+}
+
+/// Solves both puzzles of a single advent day.
+pub trait Solver: Sync {
+    /// The advent day this solver belongs to.
+    fn day(&self) -> u8;
+
+    /// The title of the day's puzzle, as shown on the aoc website.
+    fn title(&self) -> &'static str;
+
+    /// Solves part one, failing if the input is malformed.
+    fn part_one(&self, input: &str) -> Result<Answer>;
+
+    /// Solves part two, failing if the input is malformed.
+    fn part_two(&self, input: &str) -> Result<Answer>;
+
+    /// Runs only the parsing step shared by both parts and discards the result.
+    ///
+    /// Benchmarks estimate the solve time by subtracting the time of this step from the time of a
+    /// complete part, so every solver should override it.
+    fn parse(&self, _input: &str) -> Result<()> {
+        Ok(())
+    }
+
+    /// The example input given in the puzzle text, if the solver knows it.
+    fn example_input(&self) -> Option<&'static str> {
+        None
+    }
+}
+
+/// The solvers of all days of one year of puzzles.
+pub struct Year {
+    pub year: u16,
+    /// All implemented days of the year, in ascending order.
+    pub solvers: &'static [&'static dyn Solver],
+}
+
+/// All years with implemented days, in ascending order.
+pub static YEARS: &[Year] = &[Year::new(2022, year_2022::SOLVERS)];
+
+pub fn find_year(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|candidate| candidate.year == year)
+}
+
+/// Parses the complete puzzle input, failing if anything is left over.
+pub fn parse_all<'a, O, E: Debug>(
+    mut parser: impl FnMut(&'a str) -> nom::IResult<&'a str, O, E>,
+    input: &'a str,
+) -> Result<O> {
+    let (res, output) =
+        parser(input).map_err(|e| eyre!("Unable to parse the puzzle input: {e}"))?;
+    if !res.trim().is_empty() {
+        bail!("Unable to parse the puzzle input from \"{res}\" on!");
+    }
+
+    Ok(output)
+}
+
+impl Year {
+    const fn new(year: u16, solvers: &'static [&'static dyn Solver]) -> Self {
+        Self { year, solvers }
+    }
+
+    pub fn find(&self, advent_day: u8) -> Option<&'static dyn Solver> {
+        self.solvers
+            .iter()
+            .find(|solver| solver.day() == advent_day)
+            .copied()
+    }
+}
@@ -0,0 +1,95 @@
+//! Reads the letters that some puzzles draw as their answer, in the 4×6 pixel font of the aoc
+//! website.
+
+use crate::days::grid::{Grid, Position};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn read_letters_recognises_the_glyphs_separated_by_blank_columns() {
+        // Given
+        let picture = indoc! {"
+            ###..####.#..#.
+            #..#.#....#..#.
+            #..#.###..####.
+            ###..#....#..#.
+            #.#..#....#..#.
+            #..#.####.#..#.
+        "};
+        let pixels = Grid::parse(picture, |c| Ok(c == '#')).unwrap();
+
+        // When
+        let letters = read_letters(&pixels);
+
+        // Then
+        assert_eq!(letters.as_deref(), Some("REH"));
+    }
+
+    #[test]
+    fn read_letters_gives_up_on_unknown_glyphs() {
+        // Given
+        let mut pixels = Grid::new(4, 6, false);
+        pixels[Position::new(2, 1)] = true;
+
+        // When
+        let letters = read_letters(&pixels);
+
+        // Then
+        assert_eq!(letters, None);
+    }
+}
+
+const GLYPH_WIDTH: usize = 4;
+const GLYPH_HEIGHT: usize = 6;
+/// The width of a glyph plus the blank column after it.
+const GLYPH_SPACING: usize = GLYPH_WIDTH + 1;
+
+/// The pixels of all known letters, row by row.
+const GLYPHS: &[(char, &str)] = &[
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('I', ".###..#...#...#...#..###"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+/// The letters drawn by the lit pixels, or `None` if any of the glyphs is not a known letter.
+///
+/// Glyphs are read from left to right, starting in the top left corner.
+pub fn read_letters(pixels: &Grid<bool>) -> Option<String> {
+    if pixels.height() < GLYPH_HEIGHT {
+        return None;
+    }
+
+    (0..pixels.width())
+        .step_by(GLYPH_SPACING)
+        .map(|left| {
+            let is_lit = |row, column| pixels.get(Position::new(row, column)) == Some(&true);
+            let glyph: String = (0..GLYPH_HEIGHT)
+                .flat_map(|row| (left..left + GLYPH_WIDTH).map(move |column| (row, column)))
+                .map(|(row, column)| if is_lit(row, column) { '#' } else { '.' })
+                .collect();
+
+            GLYPHS
+                .iter()
+                .find(|(_, pixels)| *pixels == glyph)
+                .map(|(letter, _)| *letter)
+        })
+        .collect()
+}
@@ -0,0 +1,162 @@
+//! Shortest paths through graphs that are only given by a function returning the neighbours of a
+//! node, like the cells of a [`Grid`](crate::days::grid::Grid).
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// A line of nodes where each node leads to the next two.
+    fn skip_ahead(node: &u32) -> Vec<u32> {
+        vec![node + 1, node + 2]
+    }
+
+    #[test]
+    fn breadth_first_search_returns_the_fewest_steps_to_a_goal() {
+        // Given
+        let start = 0;
+
+        // When
+        let steps = breadth_first_search([start], skip_ahead, |&node| node == 7);
+
+        // Then
+        assert_eq!(steps, Some(4));
+    }
+
+    #[test]
+    fn breadth_first_search_starts_from_the_closest_of_several_starts() {
+        // Given
+        let starts = [0, 5];
+
+        // When
+        let steps = breadth_first_search(starts, skip_ahead, |&node| node == 7);
+
+        // Then
+        assert_eq!(steps, Some(1));
+    }
+
+    #[test]
+    fn breadth_first_search_returns_none_if_no_goal_is_reachable() {
+        // Given
+        let start = 3;
+
+        // When
+        let steps = breadth_first_search(
+            [start],
+            |&node: &u32| node.checked_sub(1),
+            |&node| node == 7,
+        );
+
+        // Then
+        assert_eq!(steps, None);
+    }
+
+    #[test]
+    fn dijkstra_returns_the_lowest_total_cost_to_a_goal() {
+        // Given
+        let edges = HashMap::from([
+            ('a', vec![('b', 1), ('c', 5)]),
+            ('b', vec![('c', 1), ('d', 7)]),
+            ('c', vec![('d', 2)]),
+        ]);
+
+        // When
+        let cost = dijkstra(
+            ['a'],
+            |node| edges.get(node).cloned().unwrap_or_default(),
+            |&node| node == 'd',
+        );
+
+        // Then
+        assert_eq!(cost, Some(4));
+    }
+}
+
+/// The fewest steps it takes to get from any of `starts` to a node for which `is_goal` is true,
+/// or `None` if no such node can be reached.
+pub fn breadth_first_search<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if visited.insert(start.clone()) {
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((node, steps)) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(steps);
+        }
+
+        for neighbour in neighbours(&node) {
+            if visited.insert(neighbour.clone()) {
+                queue.push_back((neighbour, steps + 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// The lowest total cost of getting from any of `starts` to a node for which `is_goal` is true,
+/// or `None` if no such node can be reached.
+///
+/// `neighbours` returns the neighbours of a node along with the cost of moving to each of them.
+pub fn dijkstra<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<u64>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let mut lowest_costs = HashMap::new();
+    // The heap refers to the nodes by index, so that they need not be ordered.
+    let mut nodes = Vec::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        lowest_costs.insert(start.clone(), 0);
+        heap.push(Reverse((0, nodes.len())));
+        nodes.push(start);
+    }
+
+    while let Some(Reverse((cost, index))) = heap.pop() {
+        let node = nodes[index].clone();
+        if lowest_costs.get(&node).is_some_and(|&lowest| cost > lowest) {
+            // Reached at a lower cost before.
+            continue;
+        }
+        if is_goal(&node) {
+            return Some(cost);
+        }
+
+        for (neighbour, step_cost) in neighbours(&node) {
+            let neighbour_cost = cost + step_cost;
+            if lowest_costs
+                .get(&neighbour)
+                .is_some_and(|&lowest| lowest <= neighbour_cost)
+            {
+                continue;
+            }
+
+            lowest_costs.insert(neighbour.clone(), neighbour_cost);
+            heap.push(Reverse((neighbour_cost, nodes.len())));
+            nodes.push(neighbour);
+        }
+    }
+
+    None
+}
@@ -0,0 +1,129 @@
+use color_eyre::eyre::{bail, eyre, Context};
+use color_eyre::Result;
+use std::str::FromStr;
+
+use super::Solver;
+use crate::answer::Answer;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn returns_the_number_of_calories_carried_by_the_elf_with_the_most_calories() {
+        // Given
+        let input = EXAMPLE_INPUT;
+
+        // When
+        let solution = solve_part_1(input).unwrap();
+
+        // Then
+        assert_eq!(solution, Answer::Number(24000));
+    }
+
+    #[test]
+    fn fails_for_lines_that_are_not_numbers() {
+        // Given
+        let input = "1000\nlots\n\n2000";
+
+        // When
+        let result = solve_part_1(input);
+
+        // Then
+        assert!(result.is_err());
+    }
+}
+
+const EXAMPLE_INPUT: &str = r#"1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000"#;
+
+pub struct Day1;
+
+impl Solver for Day1 {
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn title(&self) -> &'static str {
+        "Calorie Counting"
+    }
+
+    fn part_one(&self, input: &str) -> Result<Answer> {
+        solve_part_1(input)
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer> {
+        solve_part_2(input)
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        calculate_calories(input)?;
+
+        Ok(())
+    }
+
+    fn example_input(&self) -> Option<&'static str> {
+        Some(EXAMPLE_INPUT)
+    }
+}
+
+pub fn solve_part_1(input_data: &str) -> Result<Answer> {
+    let calorie_sums = calculate_calories(input_data)?;
+    let highest_sum: u32 = calorie_sums
+        .into_iter()
+        .max()
+        .ok_or_else(|| eyre!("There are no elves in the input!"))?;
+
+    Ok(highest_sum.into())
+}
+
+pub fn solve_part_2(input_data: &str) -> Result<Answer> {
+    let mut calorie_sums = calculate_calories(input_data)?;
+    if calorie_sums.len() < 3 {
+        bail!(
+            "Need at least three elves, but there are only {}!",
+            calorie_sums.len()
+        );
+    }
+    calorie_sums.sort_unstable();
+    let top_three_elves = &calorie_sums.as_slice()[calorie_sums.len() - 3..];
+    let total_calories: u32 = top_three_elves.iter().sum();
+
+    Ok(total_calories.into())
+}
+
+fn calculate_calories(input_data: &str) -> Result<Vec<u32>> {
+    let groups_of_lines = input_data.split("\n\n");
+    let number_groups: Result<Vec<_>> = groups_of_lines.map(parse_group).collect();
+    let number_groups = number_groups?;
+
+    let calorie_sums = number_groups
+        .into_iter()
+        .map(|group| group.into_iter().sum());
+    Ok(calorie_sums.collect())
+}
+
+fn parse_group(group: &str) -> Result<Vec<u32>> {
+    let lines = group.split('\n');
+
+    lines
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(idx, line)| {
+            u32::from_str(line)
+                .wrap_err_with(|| format!("Failed to parse \"{line}\" on line {idx}!"))
+        })
+        .collect()
+}
@@ -0,0 +1,408 @@
+use color_eyre::Result;
+use indoc::indoc;
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::line_ending;
+use nom::combinator::{map, value};
+use nom::multi::separated_list1;
+use nom::sequence::preceded;
+use nom::IResult;
+
+use super::Solver;
+use crate::answer::Answer;
+use crate::days::grid::{Grid, Position};
+use crate::days::ocr;
+use crate::days::parse_all;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn run_calls_the_hook_during_every_cycle_with_the_value_of_x() {
+        // Given
+        let program = [
+            Instruction::Noop,
+            Instruction::Addx(3),
+            Instruction::Addx(-5),
+        ];
+        let mut cpu = Cpu::new();
+        let mut cycles = Vec::new();
+
+        // When
+        cpu.run(&program, |cycle, x| cycles.push((cycle, x)));
+
+        // Then
+        assert_eq!(cycles, vec![(1, 1), (2, 1), (3, 1), (4, 4), (5, 4)]);
+        assert_eq!(cpu.x(), -1);
+    }
+
+    #[test]
+    fn parse_program_requires_a_line_break_between_instructions() {
+        // Given
+        let input = "noop\naddx 3noop\n";
+
+        // When
+        let result = parse_all(parse_program, input);
+
+        // Then
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Unable to parse the puzzle input from \"noop\n\" on!"
+        );
+    }
+
+    #[test]
+    fn solve_1_returns_the_sum_of_the_interesting_signal_strengths() {
+        // Given
+        // EXAMPLE_INPUT as below
+
+        // When
+        let solution = solve_part_1(EXAMPLE_INPUT).unwrap();
+
+        // Then
+        assert_eq!(solution, Answer::Number(13140));
+    }
+
+    #[test]
+    fn render_draws_the_pixels_where_the_sprite_is_during_their_cycle() {
+        // Given
+        let program = parse_all(parse_program, EXAMPLE_INPUT).unwrap();
+
+        // When
+        let screen = render(&program);
+
+        // Then
+        assert_eq!(
+            draw(&screen),
+            indoc! {"
+                ##..##..##..##..##..##..##..##..##..##..
+                ###...###...###...###...###...###...###.
+                ####....####....####....####....####....
+                #####.....#####.....#####.....#####.....
+                ######......######......######......####
+                #######.......#######.......#######.....
+            "}
+        );
+    }
+
+    #[test]
+    fn solve_2_falls_back_to_the_picture_if_it_shows_no_letters() {
+        // Given
+        // EXAMPLE_INPUT as below
+
+        // When
+        let solution = solve_part_2(EXAMPLE_INPUT).unwrap();
+
+        // Then
+        assert!(solution
+            .to_string()
+            .starts_with("##..##..##..##..##..##..##..##..##..##..\n"));
+    }
+}
+
+const EXAMPLE_INPUT: &str = indoc! {"
+    addx 15
+    addx -11
+    addx 6
+    addx -3
+    addx 5
+    addx -1
+    addx -8
+    addx 13
+    addx 4
+    noop
+    addx -1
+    addx 5
+    addx -1
+    addx 5
+    addx -1
+    addx 5
+    addx -1
+    addx 5
+    addx -1
+    addx -35
+    addx 1
+    addx 24
+    addx -19
+    addx 1
+    addx 16
+    addx -11
+    noop
+    noop
+    addx 21
+    addx -15
+    noop
+    noop
+    addx -3
+    addx 9
+    addx 1
+    addx -3
+    addx 8
+    addx 1
+    addx 5
+    noop
+    noop
+    noop
+    noop
+    noop
+    addx -36
+    noop
+    addx 1
+    addx 7
+    noop
+    noop
+    noop
+    addx 2
+    addx 6
+    noop
+    noop
+    noop
+    noop
+    noop
+    addx 1
+    noop
+    noop
+    addx 7
+    addx 1
+    noop
+    addx -13
+    addx 13
+    addx 7
+    noop
+    addx 1
+    addx -33
+    noop
+    noop
+    noop
+    addx 2
+    noop
+    noop
+    noop
+    addx 8
+    noop
+    addx -1
+    addx 2
+    addx 1
+    noop
+    addx 17
+    addx -9
+    addx 1
+    addx 1
+    addx -3
+    addx 11
+    noop
+    noop
+    addx 1
+    noop
+    addx 1
+    noop
+    noop
+    addx -13
+    addx -19
+    addx 1
+    addx 3
+    addx 26
+    addx -30
+    addx 12
+    addx -1
+    addx 3
+    addx 1
+    noop
+    noop
+    noop
+    addx -9
+    addx 18
+    addx 1
+    addx 2
+    noop
+    noop
+    addx 9
+    noop
+    noop
+    noop
+    addx -1
+    addx 2
+    addx -37
+    addx 1
+    addx 3
+    noop
+    addx 15
+    addx -21
+    addx 22
+    addx -6
+    addx 1
+    noop
+    addx 2
+    addx 1
+    noop
+    addx -10
+    noop
+    noop
+    addx 20
+    addx 1
+    addx 2
+    addx 2
+    addx -6
+    addx -11
+    noop
+    noop
+    noop
+"};
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn title(&self) -> &'static str {
+        "Cathode-Ray Tube"
+    }
+
+    fn part_one(&self, input: &str) -> Result<Answer> {
+        solve_part_1(input)
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer> {
+        solve_part_2(input)
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_all(parse_program, input)?;
+
+        Ok(())
+    }
+
+    fn example_input(&self) -> Option<&'static str> {
+        Some(EXAMPLE_INPUT)
+    }
+}
+
+pub fn solve_part_1(input_data: &str) -> Result<Answer> {
+    let program = parse_all(parse_program, input_data)?;
+
+    let mut total_signal_strength = 0;
+    Cpu::new().run(&program, |cycle, x| {
+        if cycle % 40 == 20 {
+            total_signal_strength += cycle as i64 * x as i64;
+        }
+    });
+
+    Ok(total_signal_strength.into())
+}
+
+/// Reads the letters on the screen, or returns the picture itself if it shows no known letters.
+pub fn solve_part_2(input_data: &str) -> Result<Answer> {
+    let program = parse_all(parse_program, input_data)?;
+
+    let screen = render(&program);
+    let answer = ocr::read_letters(&screen).unwrap_or_else(|| draw(&screen));
+
+    Ok(answer.into())
+}
+
+pub const SCREEN_WIDTH: usize = 40;
+pub const SCREEN_HEIGHT: usize = 6;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+impl Instruction {
+    /// The number of cycles it takes to execute the instruction.
+    fn cycles(self) -> u32 {
+        match self {
+            Instruction::Noop => 1,
+            Instruction::Addx(_) => 2,
+        }
+    }
+}
+
+/// A CPU with a single register, X.
+#[derive(Debug, Clone)]
+pub struct Cpu {
+    x: i32,
+    cycle: u32,
+}
+
+impl Cpu {
+    pub fn new() -> Self {
+        Self { x: 1, cycle: 0 }
+    }
+
+    pub fn x(&self) -> i32 {
+        self.x
+    }
+
+    /// Executes `program`, calling `on_cycle` during every cycle with the number of the cycle
+    /// (starting at 1) and the value of X during that cycle.
+    ///
+    /// X only changes after the last cycle of an instruction.
+    pub fn run(&mut self, program: &[Instruction], mut on_cycle: impl FnMut(u32, i32)) {
+        for instruction in program {
+            for _ in 0..instruction.cycles() {
+                self.cycle += 1;
+                on_cycle(self.cycle, self.x);
+            }
+
+            if let Instruction::Addx(value) = instruction {
+                self.x += value;
+            }
+        }
+    }
+}
+
+impl Default for Cpu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The screen after running `program`, with the pixels lit where the three pixels wide sprite
+/// centered on X was while the pixel was drawn.
+pub fn render(program: &[Instruction]) -> Grid<bool> {
+    let mut screen = Grid::new(SCREEN_WIDTH, SCREEN_HEIGHT, false);
+
+    Cpu::new().run(program, |cycle, x| {
+        let pixel = cycle as usize - 1;
+        let position = Position::new(pixel / SCREEN_WIDTH, pixel % SCREEN_WIDTH);
+        if let Some(lit) = screen.get_mut(position) {
+            *lit = (x - position.column as i32).abs() <= 1;
+        }
+    });
+
+    screen
+}
+
+/// The screen as text, with `#` for lit and `.` for dark pixels.
+pub fn draw(screen: &Grid<bool>) -> String {
+    (0..screen.height())
+        .map(|row| {
+            let mut line: String = screen
+                .row(row)
+                .map(|&lit| if lit { '#' } else { '.' })
+                .collect();
+            line.push('\n');
+            line
+        })
+        .collect()
+}
+
+fn parse_instruction(i: &str) -> IResult<&str, Instruction> {
+    alt((
+        value(Instruction::Noop, tag("noop")),
+        map(
+            preceded(tag("addx "), nom::character::complete::i32),
+            Instruction::Addx,
+        ),
+    ))(i)
+}
+
+pub fn parse_program(i: &str) -> IResult<&str, Vec<Instruction>> {
+    separated_list1(line_ending, parse_instruction)(i)
+}
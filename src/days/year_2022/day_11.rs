@@ -0,0 +1,443 @@
+use color_eyre::{
+    eyre::{bail, eyre},
+    Result,
+};
+use indoc::indoc;
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{line_ending, u64 as parse_u64};
+use nom::combinator::{eof, map, opt, value};
+use nom::multi::{many1, separated_list1};
+use nom::sequence::{delimited, terminated};
+use nom::Parser;
+use nom_supreme::error::ErrorTree;
+use nom_supreme::ParserExt;
+
+use super::Solver;
+use crate::answer::Answer;
+use crate::days::parse_all;
+
+pub type IResult<I, O> = nom::IResult<I, O, ErrorTree<I>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_monkey_returns_the_whole_spec_of_a_monkey() {
+        // Given
+        let input = indoc! {"
+            Monkey 2:
+              Starting items: 79, 60, 97
+              Operation: new = old * old
+              Test: divisible by 13
+                If true: throw to monkey 1
+                If false: throw to monkey 3
+        "};
+
+        // When
+        let (res, monkey) = parse_monkey(input).unwrap();
+
+        // Then
+        assert_eq!(
+            monkey,
+            Monkey {
+                id: 2,
+                items: vec![79, 60, 97],
+                operation: Operation::Multiply(Operand::Old),
+                divisor: 13,
+                target_if_divisible: 1,
+                target_otherwise: 3,
+            }
+        );
+        assert!(res.is_empty(), r#"Res not empty! Leftovers: "{res}""#);
+    }
+
+    #[test]
+    fn parse_checked_monkeys_accepts_input_without_a_final_line_break() {
+        // Given
+        let input = EXAMPLE_INPUT.trim_end();
+
+        // When
+        let monkeys = parse_checked_monkeys(input).unwrap();
+
+        // Then
+        assert_eq!(monkeys.len(), 4);
+        assert_eq!(monkeys[3].target_otherwise, 1);
+    }
+
+    #[test]
+    fn round_lets_every_monkey_throw_all_of_its_items_in_turn() {
+        // Given
+        let monkeys = parse_checked_monkeys(EXAMPLE_INPUT).unwrap();
+        let mut game = KeepAway::new(monkeys);
+
+        // When
+        game.round(|worry| worry / 3).unwrap();
+
+        // Then
+        let items: Vec<_> = game.monkeys().iter().map(|m| m.items.clone()).collect();
+        assert_eq!(
+            items,
+            vec![
+                vec![20, 23, 27, 26],
+                vec![2080, 25, 167, 207, 401, 1046],
+                vec![],
+                vec![],
+            ]
+        );
+        assert_eq!(game.inspections(), [2, 4, 3, 5]);
+    }
+
+    #[test]
+    fn solve_1_fails_for_worry_levels_that_overflow() {
+        // Given
+        let input = indoc! {"
+            Monkey 0:
+              Starting items: 4294967296
+              Operation: new = old * old
+              Test: divisible by 2
+                If true: throw to monkey 1
+                If false: throw to monkey 1
+
+            Monkey 1:
+              Starting items: 1
+              Operation: new = old + 1
+              Test: divisible by 3
+                If true: throw to monkey 0
+                If false: throw to monkey 0
+        "};
+
+        // When
+        let result = solve_part_1(input);
+
+        // Then
+        assert_eq!(result.unwrap_err().to_string(), "Worry level overflowed!");
+    }
+
+    #[test]
+    fn solve_2_fails_for_divisors_whose_common_multiple_overflows() {
+        // Given
+        let input = indoc! {"
+            Monkey 0:
+              Starting items: 1
+              Operation: new = old + 1
+              Test: divisible by 4294967296
+                If true: throw to monkey 1
+                If false: throw to monkey 1
+
+            Monkey 1:
+              Starting items: 1
+              Operation: new = old + 1
+              Test: divisible by 4294967296
+                If true: throw to monkey 0
+                If false: throw to monkey 0
+        "};
+
+        // When
+        let result = solve_part_2(input);
+
+        // Then
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "The common multiple of the divisors overflowed!"
+        );
+    }
+
+    #[test]
+    fn solve_1_returns_the_monkey_business_after_20_rounds_with_relief() {
+        // Given
+        // EXAMPLE_INPUT as below
+
+        // When
+        let solution = solve_part_1(EXAMPLE_INPUT).unwrap();
+
+        // Then
+        assert_eq!(solution, Answer::Number(10605));
+    }
+
+    #[test]
+    fn solve_2_returns_the_monkey_business_after_10000_rounds_without_relief() {
+        // Given
+        // EXAMPLE_INPUT as below
+
+        // When
+        let solution = solve_part_2(EXAMPLE_INPUT).unwrap();
+
+        // Then
+        assert_eq!(solution, Answer::Number(2713310158));
+    }
+}
+
+const EXAMPLE_INPUT: &str = indoc! {"
+    Monkey 0:
+      Starting items: 79, 98
+      Operation: new = old * 19
+      Test: divisible by 23
+        If true: throw to monkey 2
+        If false: throw to monkey 3
+
+    Monkey 1:
+      Starting items: 54, 65, 75, 74
+      Operation: new = old + 6
+      Test: divisible by 19
+        If true: throw to monkey 2
+        If false: throw to monkey 0
+
+    Monkey 2:
+      Starting items: 79, 60, 97
+      Operation: new = old * old
+      Test: divisible by 13
+        If true: throw to monkey 1
+        If false: throw to monkey 3
+
+    Monkey 3:
+      Starting items: 74
+      Operation: new = old + 3
+      Test: divisible by 17
+        If true: throw to monkey 0
+        If false: throw to monkey 1
+"};
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn title(&self) -> &'static str {
+        "Monkey in the Middle"
+    }
+
+    fn part_one(&self, input: &str) -> Result<Answer> {
+        solve_part_1(input)
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer> {
+        solve_part_2(input)
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_checked_monkeys(input)?;
+
+        Ok(())
+    }
+
+    fn example_input(&self) -> Option<&'static str> {
+        Some(EXAMPLE_INPUT)
+    }
+}
+
+pub fn solve_part_1(input_data: &str) -> Result<Answer> {
+    let mut game = KeepAway::new(parse_checked_monkeys(input_data)?);
+
+    for _ in 0..20 {
+        game.round(|worry| worry / 3)?;
+    }
+
+    Ok(game.monkey_business().into())
+}
+
+pub fn solve_part_2(input_data: &str) -> Result<Answer> {
+    let mut game = KeepAway::new(parse_checked_monkeys(input_data)?);
+
+    // Taking the worry levels modulo a multiple of all divisors keeps them small without changing
+    // where any of the items are thrown.
+    let modulus = game.common_multiple()?;
+    for _ in 0..10_000 {
+        game.round(|worry| worry % modulus)?;
+    }
+
+    Ok(game.monkey_business().into())
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Operand {
+    Old,
+    Value(u64),
+}
+
+/// How inspecting an item changes its worry level.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Operation {
+    Add(Operand),
+    Multiply(Operand),
+}
+
+impl Operation {
+    fn apply(self, old: u64) -> Result<u64> {
+        let operand = |operand| match operand {
+            Operand::Old => old,
+            Operand::Value(value) => value,
+        };
+
+        let new = match self {
+            Operation::Add(other) => old.checked_add(operand(other)),
+            Operation::Multiply(other) => old.checked_mul(operand(other)),
+        };
+
+        new.ok_or_else(|| eyre!("Worry level overflowed!"))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Monkey {
+    pub id: usize,
+    /// The worry levels of the items the monkey holds, in the order it inspects them.
+    pub items: Vec<u64>,
+    pub operation: Operation,
+    pub divisor: u64,
+    pub target_if_divisible: usize,
+    pub target_otherwise: usize,
+}
+
+impl Monkey {
+    /// The monkey to throw an item with the given worry level to.
+    fn target(&self, worry: u64) -> usize {
+        if worry.is_multiple_of(self.divisor) {
+            self.target_if_divisible
+        } else {
+            self.target_otherwise
+        }
+    }
+}
+
+/// The game of the monkeys, keeping track of how many items each monkey inspected.
+pub struct KeepAway {
+    monkeys: Vec<Monkey>,
+    inspections: Vec<usize>,
+}
+
+impl KeepAway {
+    /// Starts a game of monkeys that all throw to each other, as checked by [`parse_checked_monkeys`].
+    pub fn new(monkeys: Vec<Monkey>) -> Self {
+        let inspections = vec![0; monkeys.len()];
+
+        Self {
+            monkeys,
+            inspections,
+        }
+    }
+
+    pub fn monkeys(&self) -> &[Monkey] {
+        &self.monkeys
+    }
+
+    /// The number of items each monkey inspected so far.
+    pub fn inspections(&self) -> &[usize] {
+        &self.inspections
+    }
+
+    /// The product of all divisors, which does not change whether a worry level is divisible by
+    /// any of them when taken modulo.
+    pub fn common_multiple(&self) -> Result<u64> {
+        self.monkeys
+            .iter()
+            .try_fold(1u64, |product, monkey| product.checked_mul(monkey.divisor))
+            .ok_or_else(|| eyre!("The common multiple of the divisors overflowed!"))
+    }
+
+    /// Lets every monkey inspect and throw all of its items in turn.
+    ///
+    /// After each inspection, `relief` turns the new worry level into the one the item is thrown
+    /// with. Fails if a worry level overflows.
+    pub fn round(&mut self, relief: impl Fn(u64) -> u64) -> Result<()> {
+        for index in 0..self.monkeys.len() {
+            let items = std::mem::take(&mut self.monkeys[index].items);
+            self.inspections[index] += items.len();
+
+            for worry in items {
+                let monkey = &self.monkeys[index];
+                let worry = relief(monkey.operation.apply(worry)?);
+                let target = monkey.target(worry);
+
+                self.monkeys[target].items.push(worry);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The product of the numbers of inspections of the two most active monkeys.
+    pub fn monkey_business(&self) -> usize {
+        let mut inspections = self.inspections.clone();
+        inspections.sort_unstable_by(|a, b| b.cmp(a));
+
+        inspections.iter().take(2).product()
+    }
+}
+
+fn parse_operand(i: &str) -> IResult<&str, Operand> {
+    alt((
+        value(Operand::Old, tag("old")),
+        map(parse_u64, Operand::Value),
+    ))(i)
+}
+
+fn parse_operation(i: &str) -> IResult<&str, Operation> {
+    let (res, _) = tag("new = old ")(i)?;
+    alt((
+        map(tag("+ ").precedes(parse_operand), Operation::Add),
+        map(tag("* ").precedes(parse_operand), Operation::Multiply),
+    ))(res)
+}
+
+fn parse_line<'a, O>(
+    prefix: &'static str,
+    parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl Parser<&'a str, O, ErrorTree<&'a str>> {
+    // The last line of the input may lack its line break.
+    delimited(tag(prefix), parser, alt((line_ending, eof))).context(prefix.trim())
+}
+
+pub fn parse_monkey(i: &str) -> IResult<&str, Monkey> {
+    let (res, id) = parse_line("Monkey ", terminated(parse_u64, tag(":"))).parse(i)?;
+    let (res, items) =
+        parse_line("  Starting items: ", separated_list1(tag(", "), parse_u64)).parse(res)?;
+    let (res, operation) = parse_line("  Operation: ", parse_operation).parse(res)?;
+    let (res, divisor) = parse_line("  Test: divisible by ", parse_u64).parse(res)?;
+    let (res, target_if_divisible) =
+        parse_line("    If true: throw to monkey ", parse_u64).parse(res)?;
+    let (res, target_otherwise) =
+        parse_line("    If false: throw to monkey ", parse_u64).parse(res)?;
+
+    Ok((
+        res,
+        Monkey {
+            id: id as usize,
+            items,
+            operation,
+            divisor,
+            target_if_divisible: target_if_divisible as usize,
+            target_otherwise: target_otherwise as usize,
+        },
+    ))
+}
+
+pub fn parse_monkeys(i: &str) -> IResult<&str, Vec<Monkey>> {
+    many1(terminated(parse_monkey, opt(line_ending)))(i)
+}
+
+/// Parses the complete puzzle input, failing if anything is left over or if the monkeys are not
+/// numbered in order or throw to monkeys that do not exist.
+pub fn parse_checked_monkeys(input_data: &str) -> Result<Vec<Monkey>> {
+    let monkeys = parse_all(parse_monkeys, input_data)?;
+
+    for (index, monkey) in monkeys.iter().enumerate() {
+        if monkey.id != index {
+            bail!("Monkey {} is listed as monkey {index}!", monkey.id);
+        }
+        if monkey.divisor == 0 {
+            bail!("Monkey {index} tests for divisibility by zero!");
+        }
+        for target in [monkey.target_if_divisible, monkey.target_otherwise] {
+            if target >= monkeys.len() || target == index {
+                bail!("Monkey {index} throws to monkey {target}, which is not another monkey!");
+            }
+        }
+    }
+
+    Ok(monkeys)
+}
@@ -0,0 +1,159 @@
+use color_eyre::{eyre::eyre, Result};
+use indoc::indoc;
+
+use super::Solver;
+use crate::answer::Answer;
+use crate::days::grid::{Grid, Position};
+use crate::days::search::breadth_first_search;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_height_map_finds_start_and_end_and_their_elevations() {
+        // Given
+        // EXAMPLE_INPUT as below
+
+        // When
+        let map = parse_height_map(EXAMPLE_INPUT).unwrap();
+
+        // Then
+        assert_eq!(map.start, Position::new(0, 0));
+        assert_eq!(map.end, Position::new(2, 5));
+        assert_eq!((map.heights[map.start], map.heights[map.end]), (0, 25));
+    }
+
+    #[test]
+    fn solve_1_returns_the_fewest_steps_from_the_start_to_the_end() {
+        // Given
+        // EXAMPLE_INPUT as below
+
+        // When
+        let solution = solve_part_1(EXAMPLE_INPUT).unwrap();
+
+        // Then
+        assert_eq!(solution, Answer::Number(31));
+    }
+
+    #[test]
+    fn solve_2_returns_the_fewest_steps_from_any_lowest_square_to_the_end() {
+        // Given
+        // EXAMPLE_INPUT as below
+
+        // When
+        let solution = solve_part_2(EXAMPLE_INPUT).unwrap();
+
+        // Then
+        assert_eq!(solution, Answer::Number(29));
+    }
+}
+
+const EXAMPLE_INPUT: &str = indoc! {"
+    Sabqponm
+    abcryxxl
+    accszExk
+    acctuvwj
+    abdefghi
+"};
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn title(&self) -> &'static str {
+        "Hill Climbing Algorithm"
+    }
+
+    fn part_one(&self, input: &str) -> Result<Answer> {
+        solve_part_1(input)
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer> {
+        solve_part_2(input)
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_height_map(input)?;
+
+        Ok(())
+    }
+
+    fn example_input(&self) -> Option<&'static str> {
+        Some(EXAMPLE_INPUT)
+    }
+}
+
+pub fn solve_part_1(input_data: &str) -> Result<Answer> {
+    let map = parse_height_map(input_data)?;
+
+    let steps = map.fewest_steps_from([map.start])?;
+
+    Ok(steps.into())
+}
+
+pub fn solve_part_2(input_data: &str) -> Result<Answer> {
+    let map = parse_height_map(input_data)?;
+
+    let steps = map.fewest_steps_from(map.heights.positions().filter(|&p| map.heights[p] == 0))?;
+
+    Ok(steps.into())
+}
+
+pub struct HeightMap {
+    /// The elevation of every square, from 0 for `a` to 25 for `z`.
+    pub heights: Grid<u8>,
+    pub start: Position,
+    pub end: Position,
+}
+
+impl HeightMap {
+    /// The fewest steps from any of `starts` to the end, climbing at most one unit per step.
+    pub fn fewest_steps_from(&self, starts: impl IntoIterator<Item = Position>) -> Result<usize> {
+        let climbable = |from: &Position| {
+            let highest_reachable = self.heights[*from] + 1;
+            self.heights
+                .neighbours(*from)
+                .filter(move |&to| self.heights[to] <= highest_reachable)
+        };
+
+        breadth_first_search(starts, climbable, |&position| position == self.end)
+            .ok_or_else(|| eyre!("The end cannot be reached!"))
+    }
+}
+
+/// Parses the height map, where `S` marks the start at elevation `a` and `E` the end at
+/// elevation `z`.
+pub fn parse_height_map(input_data: &str) -> Result<HeightMap> {
+    let squares = Grid::parse(input_data, |c| match c {
+        'a'..='z' | 'S' | 'E' => Ok(c),
+        _ => Err(eyre!("'{c}' is not a square of the height map!")),
+    })?;
+
+    let start = squares
+        .position(|&square| square == 'S')
+        .ok_or_else(|| eyre!("The height map has no start!"))?;
+    let end = squares
+        .position(|&square| square == 'E')
+        .ok_or_else(|| eyre!("The height map has no end!"))?;
+
+    let heights = squares.map(|&square| {
+        let elevation = match square {
+            'S' => 'a',
+            'E' => 'z',
+            square => square,
+        };
+
+        elevation as u8 - b'a'
+    });
+
+    Ok(HeightMap {
+        heights,
+        start,
+        end,
+    })
+}
@@ -3,69 +3,104 @@ use color_eyre::{
     eyre::{bail, eyre, Context},
     Result,
 };
+use indoc::indoc;
+
+use super::Solver;
+use crate::answer::Answer;
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
 
     #[test]
     fn part_one_returns_the_score_of_rock_paper_scissors_with_second_column_is_own_action() {
         // Given
-        let input = indoc! {"
-            A Y
-            B X
-            C Z"};
+        let input = EXAMPLE_INPUT;
 
         // When
-        let solution = solve_part_1(input);
+        let solution = solve_part_1(input).unwrap();
 
         // Then
-        assert_eq!(solution, "15");
+        assert_eq!(solution, Answer::Number(15));
     }
 
     #[test]
     fn part_two_returns_the_score_of_rock_paper_scissors_with_second_column_as_outcome() {
         // Given
-        let input = indoc! {"
-            A Y
-            B X
-            C Z"};
+        let input = EXAMPLE_INPUT;
 
         // When
-        let solution = solve_part_2(input);
+        let solution = solve_part_2(input).unwrap();
 
         // Then
-        assert_eq!(solution, "12");
+        assert_eq!(solution, Answer::Number(12));
     }
 }
 
-pub fn solve_part_1(input: &str) -> String {
-    let rows = input.trim().split('\n');
-    let games = rows.enumerate().map(|(idx, row)| {
-        GamePartOne::try_from(row)
-            .wrap_err_with(|| format!("Unable to parse row \"{row}\" ({idx})!"))
-            .unwrap()
-    });
+const EXAMPLE_INPUT: &str = indoc! {"
+    A Y
+    B X
+    C Z"};
+
+pub struct Day2;
+
+impl Solver for Day2 {
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn title(&self) -> &'static str {
+        "Rock Paper Scissors"
+    }
+
+    fn part_one(&self, input: &str) -> Result<Answer> {
+        solve_part_1(input)
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer> {
+        solve_part_2(input)
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_games::<GamePartOne>(input)?;
 
-    let scores = games.map(|game| game.score());
+        Ok(())
+    }
+
+    fn example_input(&self) -> Option<&'static str> {
+        Some(EXAMPLE_INPUT)
+    }
+}
+
+pub fn solve_part_1(input: &str) -> Result<Answer> {
+    let games = parse_games::<GamePartOne>(input)?;
+
+    let scores = games.iter().map(|game| game.score());
     let total_score: i32 = scores.sum();
 
-    total_score.to_string()
+    Ok(total_score.into())
 }
 
-pub fn solve_part_2(input: &str) -> String {
+pub fn solve_part_2(input: &str) -> Result<Answer> {
+    let games = parse_games::<GamePartTwo>(input)?;
+
+    let scores = games.iter().map(|game| game.score());
+    let total_score: i32 = scores.sum();
+
+    Ok(total_score.into())
+}
+
+/// Parses one game per row, reading the second column as the part `G` belongs to does.
+fn parse_games<'a, G>(input: &'a str) -> Result<Vec<G>>
+where
+    G: TryFrom<&'a str, Error = eyre::Error>,
+{
     let rows = input.trim().split('\n');
     let games = rows.enumerate().map(|(idx, row)| {
-        GamePartTwo::try_from(row)
-            .wrap_err_with(|| format!("Unable to parse row \"{row}\" ({idx})!"))
-            .unwrap()
+        G::try_from(row).wrap_err_with(|| format!("Unable to parse row \"{row}\" ({idx})!"))
     });
 
-    let scores = games.map(|game| game.score());
-    let total_score: i32 = scores.sum();
-
-    total_score.to_string()
+    games.collect()
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
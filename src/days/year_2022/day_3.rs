@@ -0,0 +1,267 @@
+use color_eyre::{
+    eyre::{bail, Context},
+    Result,
+};
+use indoc::indoc;
+use itertools::Itertools;
+use std::collections::HashSet;
+
+use super::Solver;
+use crate::answer::Answer;
+use crate::days::groups::Groups;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_fault_returns_the_only_character_that_occurs_in_both_compartment() {
+        // Given
+        let rucksack = "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL";
+
+        // When
+        let fault = find_fault(rucksack).unwrap();
+
+        // Then
+        assert_eq!(fault, 'L');
+    }
+
+    #[test]
+    fn items_have_a_priority_matching_their_ascii_values() {
+        // Given
+        let items = "pLPvts".chars();
+
+        // When
+        let priorities: Vec<_> = items.map(score_item).collect::<Result<_>>().unwrap();
+
+        // Then
+        assert_eq!(priorities.iter().sum::<u32>(), 157);
+    }
+
+    #[test]
+    fn score_item_fails_for_anything_but_letters() {
+        // Given
+        let item = '1';
+
+        // When
+        let result = score_item(item);
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn find_fault_fails_for_rucksacks_with_non_ascii_items() {
+        // Given
+        let rucksack = "aéba";
+
+        // When
+        let result = find_fault(rucksack);
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn solve_1_fails_for_faults_that_are_not_letters() {
+        // Given
+        let input = "1a1b\n";
+
+        // When
+        let result = solve_part_1(input);
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn solve_1_returns_the_sum_of_priorities_for_all_faulty_items() {
+        // Given
+        let input = EXAMPLE_INPUT.trim();
+
+        // When
+        let solution = solve_part_1(input).unwrap();
+
+        // Then
+        assert_eq!(solution, Answer::Number(157));
+    }
+
+    #[test]
+    fn find_badge_returns_the_item_that_is_common_for_all_elves_in_the_group() {
+        // Given
+        let items = [
+            "vJrwpWtwJgWrhcsFMMfFFhFp",
+            "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+            "PmmdzqPrVvPwwTWBwg",
+        ];
+
+        // When
+        let badge = find_badge(&items).unwrap();
+
+        // Then
+        assert_eq!(badge, 'r');
+    }
+
+    #[test]
+    fn solve_2_returns_the_sum_of_priorities_for_all_group_badges() {
+        // Given
+        let input = EXAMPLE_INPUT.trim();
+
+        // When
+        let solution = solve_part_2(input).unwrap();
+
+        // Then
+        assert_eq!(solution, Answer::Number(70));
+    }
+
+    #[test]
+    fn solve_2_fails_for_an_incomplete_last_group() {
+        // Given
+        let input = indoc! {"
+            vJrwpWtwJgWrhcsFMMfFFhFp
+            jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+        "};
+
+        // When
+        let result = solve_part_2(input);
+
+        // Then
+        assert!(result.is_err());
+    }
+}
+
+const EXAMPLE_INPUT: &str = indoc! {
+    "vJrwpWtwJgWrhcsFMMfFFhFp
+     jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+     PmmdzqPrVvPwwTWBwg
+     wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+     ttgJtRGJQctTZtZT
+     CrZsJsPPZsGzwwsLwLmpwMDw"
+};
+
+pub struct Day3;
+
+impl Solver for Day3 {
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn title(&self) -> &'static str {
+        "Rucksack Reorganization"
+    }
+
+    fn part_one(&self, input: &str) -> Result<Answer> {
+        solve_part_1(input)
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer> {
+        solve_part_2(input)
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_rucksacks(input)?;
+
+        Ok(())
+    }
+
+    fn example_input(&self) -> Option<&'static str> {
+        Some(EXAMPLE_INPUT)
+    }
+}
+
+pub fn solve_part_1(input_data: &str) -> Result<Answer> {
+    let rucksacks = parse_rucksacks(input_data)?;
+    let mut total_score = 0;
+    for rucksack in rucksacks {
+        let fault = find_fault(rucksack)
+            .wrap_err_with(|| format!("Unable to find the fault in rucksack \"{rucksack}\"!"))?;
+        let score = score_item(fault)?;
+
+        total_score += score;
+    }
+
+    Ok(total_score.into())
+}
+
+pub fn solve_part_2(input_data: &str) -> Result<Answer> {
+    let rucksacks = parse_rucksacks(input_data)?;
+    let mut groups = rucksacks.into_iter().groups::<3>();
+
+    let mut total_score = 0;
+    for group in groups.by_ref() {
+        let badge = find_badge(&group)
+            .wrap_err_with(|| format!("Unable to find the badge of group {group:?}!"))?;
+        let score = score_item(badge)?;
+
+        total_score += score;
+    }
+    if !groups.remainder().is_empty() {
+        bail!("The last group is incomplete: {:?}!", groups.remainder());
+    }
+
+    Ok(total_score.into())
+}
+
+/// One rucksack per line, holding only items that are letters.
+fn parse_rucksacks(input_data: &str) -> Result<Vec<&str>> {
+    let rucksacks = input_data.trim().split('\n');
+    rucksacks
+        .map(|rucksack| {
+            if let Some(item) = rucksack.chars().find(|item| !item.is_ascii_alphabetic()) {
+                bail!("'{item}' in rucksack \"{rucksack}\" is not an item!");
+            }
+            Ok(rucksack)
+        })
+        .collect()
+}
+
+fn score_item(item: char) -> Result<u32> {
+    if !item.is_ascii_alphabetic() {
+        bail!("'{item}' is not an item!");
+    }
+
+    let lowercase_item = item.to_ascii_lowercase();
+    let lowercase_priority = lowercase_item as u32 - 'a' as u32 + 1;
+
+    if item.is_ascii_uppercase() {
+        Ok(lowercase_priority + 26)
+    } else {
+        Ok(lowercase_priority)
+    }
+}
+
+fn find_fault(rucksack: &str) -> Result<char> {
+    // Only ASCII rucksacks can be split in half at any byte.
+    if !rucksack.is_ascii() {
+        bail!("The rucksack contains items that are not ASCII letters!");
+    }
+
+    let (first_compartment, second_compartment) = rucksack.split_at(rucksack.len() / 2);
+
+    let item_types_in_first_compartment: HashSet<_> = first_compartment.chars().collect();
+    let item_types_in_second_compartment: HashSet<_> = second_compartment.chars().collect();
+
+    let item_types_in_both_compartments: Vec<_> = item_types_in_first_compartment
+        .intersection(&item_types_in_second_compartment)
+        .cloned()
+        .collect();
+
+    let only_overlapping_item = item_types_in_both_compartments.into_iter().exactly_one()?;
+
+    Ok(only_overlapping_item)
+}
+
+fn find_badge(rucksacks: &[&str; 3]) -> Result<char> {
+    let item_types = rucksacks.map(|rucksack| rucksack.chars().collect::<HashSet<_>>());
+    let first_two_common_items: HashSet<_> = item_types[0]
+        .intersection(&item_types[1])
+        .cloned()
+        .collect();
+    let possible_badges: Vec<_> = first_two_common_items
+        .intersection(&item_types[2])
+        .cloned()
+        .collect();
+    let badge = possible_badges.into_iter().exactly_one()?;
+
+    Ok(badge)
+}
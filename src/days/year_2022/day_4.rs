@@ -1,22 +1,16 @@
 use color_eyre::{
-    eyre::{eyre, Context},
+    eyre::{bail, eyre, Context},
     Result,
 };
+use indoc::indoc;
 use std::ops::RangeInclusive;
 
+use super::Solver;
+use crate::answer::Answer;
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
-
-    const INPUT: &str = indoc! {
-        "2-4,6-8
-         2-3,4-5
-         5-7,7-9
-         2-8,3-7
-         6-6,4-6
-         2-6,4-8"
-    };
 
     #[test]
     fn parse_pair_returns_pair_of_ranges() {
@@ -34,30 +28,81 @@ mod tests {
     #[test]
     fn solve_1_returns_the_number_of_fully_overlapping_ranges() {
         // Given
-        // INPUT as above
+        // EXAMPLE_INPUT as below
 
         // When
-        let solution = solve_part_1(INPUT.trim());
+        let solution = solve_part_1(EXAMPLE_INPUT.trim()).unwrap();
 
         // Then
-        assert_eq!(solution, "2");
+        assert_eq!(solution, Answer::Number(2));
     }
 
     #[test]
     fn solve_2_returns_the_number_of_all_overlapping_ranges() {
         // Given
-        // INPUT as above
+        // EXAMPLE_INPUT as below
+
+        // When
+        let solution = solve_part_2(EXAMPLE_INPUT.trim()).unwrap();
+
+        // Then
+        assert_eq!(solution, Answer::Number(4));
+    }
+
+    #[test]
+    fn solve_1_fails_for_unpaired_ranges() {
+        // Given
+        let input = "2-4,6-8\n2-3";
 
         // When
-        let solution = solve_part_2(INPUT.trim());
+        let result = solve_part_1(input);
 
         // Then
-        assert_eq!(solution, "4");
+        assert!(result.is_err());
+    }
+}
+
+const EXAMPLE_INPUT: &str = indoc! {
+    "2-4,6-8
+     2-3,4-5
+     5-7,7-9
+     2-8,3-7
+     6-6,4-6
+     2-6,4-8"
+};
+
+pub struct Day4;
+
+impl Solver for Day4 {
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn title(&self) -> &'static str {
+        "Camp Cleanup"
+    }
+
+    fn part_one(&self, input: &str) -> Result<Answer> {
+        solve_part_1(input)
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer> {
+        solve_part_2(input)
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_input(input)?;
+
+        Ok(())
+    }
+
+    fn example_input(&self) -> Option<&'static str> {
+        Some(EXAMPLE_INPUT)
     }
 }
 
-pub fn solve_part_1(input_data: &str) -> String {
-    let groups = parse_input(input_data).unwrap();
+pub fn solve_part_1(input_data: &str) -> Result<Answer> {
+    let groups = parse_input(input_data)?;
 
     let mut total_fully_overlapping_groups = 0;
     for (first_range, second_range) in groups.iter() {
@@ -70,11 +115,11 @@ pub fn solve_part_1(input_data: &str) -> String {
         }
     }
 
-    total_fully_overlapping_groups.to_string()
+    Ok(total_fully_overlapping_groups.into())
 }
 
-pub fn solve_part_2(input_data: &str) -> String {
-    let groups = parse_input(input_data).unwrap();
+pub fn solve_part_2(input_data: &str) -> Result<Answer> {
+    let groups = parse_input(input_data)?;
 
     let mut total_overlapping_groups = 0;
     for (first_range, second_range) in groups.iter() {
@@ -87,7 +132,7 @@ pub fn solve_part_2(input_data: &str) -> String {
         }
     }
 
-    total_overlapping_groups.to_string()
+    Ok(total_overlapping_groups.into())
 }
 
 fn parse_input(input_data: &str) -> Result<Vec<(RangeInclusive<u32>, RangeInclusive<u32>)>> {
@@ -115,7 +160,9 @@ fn parse_pair(raw_pair: &str) -> Result<(RangeInclusive<u32>, RangeInclusive<u32
     let halves = raw_pair.split(',');
     let ranges: Result<Vec<_>> = halves.map(parse_range).collect();
     let ranges = ranges.wrap_err_with(|| format!("Unable to parse ranges from {raw_pair}!"))?;
-    assert_eq!(ranges.len(), 2);
+    if ranges.len() != 2 {
+        bail!("Expected a pair of ranges, got {raw_pair}!");
+    }
 
     Ok((ranges[0].clone(), ranges[1].clone()))
 }
@@ -1,10 +1,17 @@
+use color_eyre::{eyre::eyre, Result};
+use indoc::indoc;
 use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::character::complete::{char, line_ending, one_of, space0};
 use nom::combinator::opt;
+use nom::error::{Error, ErrorKind};
 use nom::multi::many1;
 use nom::IResult;
 
+use super::Solver;
+use crate::answer::Answer;
+use crate::days::parse_all;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -150,104 +157,171 @@ mod tests {
     #[test]
     fn solve_part_1_executes_instructions_and_returns_character_from_top_of_each_stack() {
         // Given
-        let input = indoc! {
-            "    [D]
-             [N] [C]
-             [Z] [M] [P]
-              1   2   3
-
-             move 1 from 2 to 1
-             move 3 from 1 to 3
-             move 2 from 2 to 1
-             move 1 from 1 to 2"
-        };
-        let expected_solution = "CMZ";
+        let input = EXAMPLE_INPUT;
+        let expected_solution = Answer::from("CMZ");
 
         // When
-        let actual_solution = solve_part_1(input);
+        let actual_solution = solve_part_1(input).unwrap();
 
         // Then
         assert_eq!(actual_solution, expected_solution);
     }
+
+    #[test]
+    fn solve_part_1_fails_for_rows_with_more_containers_than_stacks() {
+        // Given
+        let input = indoc! {"
+            [A] [B] [C]
+             1   2
+
+            move 1 from 1 to 2
+        "};
+
+        // When
+        let result = solve_part_1(input);
+
+        // Then
+        assert!(result.is_err());
+    }
 }
 
-pub fn solve_part_1(input_data: &str) -> String {
-    let (
-        _,
-        PuzzleInput {
-            mut stacks,
-            instructions,
-        },
-    ) = parse_input(input_data).unwrap();
+const EXAMPLE_INPUT: &str = indoc! {
+    "    [D]
+     [N] [C]
+     [Z] [M] [P]
+      1   2   3
+
+     move 1 from 2 to 1
+     move 3 from 1 to 3
+     move 2 from 2 to 1
+     move 1 from 1 to 2"
+};
+
+pub struct Day5;
+
+impl Solver for Day5 {
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn title(&self) -> &'static str {
+        "Supply Stacks"
+    }
+
+    fn part_one(&self, input: &str) -> Result<Answer> {
+        solve_part_1(input)
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer> {
+        solve_part_2(input)
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_all(parse_input, input)?;
+
+        Ok(())
+    }
+
+    fn example_input(&self) -> Option<&'static str> {
+        Some(EXAMPLE_INPUT)
+    }
+}
+
+pub fn solve_part_1(input_data: &str) -> Result<Answer> {
+    let PuzzleInput {
+        mut stacks,
+        instructions,
+    } = parse_all(parse_input, input_data)?;
 
     for instruction in instructions {
         // println!("Current stack state: {stacks:#?}");
         // println!("Executing instruction: {instruction:#?}");
-        let stack_from = stacks.get_mut(instruction.from as usize - 1).unwrap();
+        let stack_from = stack_mut(&mut stacks, instruction.from)?;
 
-        let splitting_point = stack_from.len() - instruction.count as usize;
+        let splitting_point = splitting_point(stack_from, &instruction)?;
         let mut payload: Vec<_> = stack_from.drain(splitting_point..).collect();
         payload.reverse();
 
-        let stack_to = stacks.get_mut(instruction.to as usize - 1).unwrap();
+        let stack_to = stack_mut(&mut stacks, instruction.to)?;
         stack_to.extend_from_slice(&payload);
     }
 
-    let mut stack_characters = Vec::new();
-    for stack in stacks {
-        let top_container = stack.last().unwrap();
-        stack_characters.push(top_container.identifier);
-    }
-
-    stack_characters.into_iter().collect()
+    top_containers(stacks)
 }
 
-pub fn solve_part_2(input_data: &str) -> String {
-    let (
-        _,
-        PuzzleInput {
-            mut stacks,
-            instructions,
-        },
-    ) = parse_input(input_data).unwrap();
+pub fn solve_part_2(input_data: &str) -> Result<Answer> {
+    let PuzzleInput {
+        mut stacks,
+        instructions,
+    } = parse_all(parse_input, input_data)?;
 
     for instruction in instructions {
         // println!("Current stack state: {stacks:#?}");
         // println!("Executing instruction: {instruction:#?}");
-        let stack_from = stacks.get_mut(instruction.from as usize - 1).unwrap();
+        let stack_from = stack_mut(&mut stacks, instruction.from)?;
 
-        let splitting_point = stack_from.len() - instruction.count as usize;
+        let splitting_point = splitting_point(stack_from, &instruction)?;
         let payload: Vec<_> = stack_from.drain(splitting_point..).collect();
 
-        let stack_to = stacks.get_mut(instruction.to as usize - 1).unwrap();
+        let stack_to = stack_mut(&mut stacks, instruction.to)?;
         stack_to.extend_from_slice(&payload);
     }
 
+    top_containers(stacks)
+}
+
+/// The stack with the given 1-based number.
+fn stack_mut(stacks: &mut [Vec<Container>], number: u32) -> Result<&mut Vec<Container>> {
+    (number as usize)
+        .checked_sub(1)
+        .and_then(|index| stacks.get_mut(index))
+        .ok_or_else(|| eyre!("There is no stack {number}!"))
+}
+
+/// The index of the first container that the instruction moves away from `stack_from`.
+fn splitting_point(stack_from: &[Container], instruction: &Instruction) -> Result<usize> {
+    stack_from
+        .len()
+        .checked_sub(instruction.count as usize)
+        .ok_or_else(|| {
+            eyre!(
+                "Unable to move {} containers from stack {}, which only holds {}!",
+                instruction.count,
+                instruction.from,
+                stack_from.len()
+            )
+        })
+}
+
+fn top_containers(stacks: Vec<Vec<Container>>) -> Result<Answer> {
     let mut stack_characters = Vec::new();
-    for stack in stacks {
-        let top_container = stack.last().unwrap();
+    for (idx, stack) in stacks.into_iter().enumerate() {
+        let top_container = stack
+            .last()
+            .ok_or_else(|| eyre!("Stack {} ends up empty!", idx + 1))?;
         stack_characters.push(top_container.identifier);
     }
 
-    stack_characters.into_iter().collect()
+    Ok(stack_characters.into_iter().collect::<String>().into())
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
-struct Container {
-    identifier: char,
+pub struct Container {
+    pub identifier: char,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
-struct Instruction {
-    from: u32,
-    to: u32,
-    count: u32,
+pub struct Instruction {
+    pub from: u32,
+    pub to: u32,
+    pub count: u32,
 }
 
+/// The stacks of containers, bottom to top, and the instructions of the crane operator.
 #[derive(Debug, Clone, Eq, PartialEq)]
-struct PuzzleInput {
-    stacks: Vec<Vec<Container>>,
-    instructions: Vec<Instruction>,
+pub struct PuzzleInput {
+    pub stacks: Vec<Vec<Container>>,
+    pub instructions: Vec<Instruction>,
 }
 
 fn parse_container(i: &str) -> IResult<&str, Option<Container>> {
@@ -299,6 +373,10 @@ fn parse_stacks(i: &str) -> IResult<&str, Vec<Vec<Container>>> {
     let (res, number_of_stacks) = parse_legend_into_column_count(res)?;
 
     let mut stacks = vec![Vec::default(); number_of_stacks as usize];
+    if stack_rows.iter().any(|row| row.len() > stacks.len()) {
+        // A row with more columns than the legend labels.
+        return Err(nom::Err::Failure(Error::new(i, ErrorKind::Verify)));
+    }
 
     for row in stack_rows.into_iter().rev() {
         for (column_idx, maybe_container) in row.into_iter().enumerate() {
@@ -323,7 +401,7 @@ fn parse_instruction(i: &str) -> IResult<&str, Instruction> {
     Ok((res, Instruction { from, to, count }))
 }
 
-fn parse_input(i: &str) -> IResult<&str, PuzzleInput> {
+pub fn parse_input(i: &str) -> IResult<&str, PuzzleInput> {
     let (res, stacks) = parse_stacks(i)?;
     let (res, _blank_line) = line_ending(res)?;
     let (res, instructions) = many1(parse_instruction)(res)?;
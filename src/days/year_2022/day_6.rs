@@ -0,0 +1,115 @@
+use color_eyre::eyre::{bail, eyre};
+use color_eyre::Result;
+use std::collections::HashSet;
+
+use super::Solver;
+use crate::answer::Answer;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yare::parameterized;
+
+    #[parameterized(
+        first =  { "mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7},
+        second = { "bvwbjplbgvbhsrlpgdmjqwftvncz", 5},
+        third = { "nppdvjthqldpwncqszvftbrmjlhg", 6},
+        fourth = { "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10},
+        fifth = { "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11},
+    )]
+    fn part_1_returns_the_number_of_characters_until_the_end_of_the_packet_start_marker(
+        input: &str,
+        expected: usize,
+    ) {
+        // Given
+
+        // When
+        let solution = solve_part_1(input).unwrap();
+
+        // Then
+        assert_eq!(solution, Answer::from(expected));
+    }
+
+    #[parameterized(
+    first =  { "mjqjpqmgbljsphdztnvjfqwrcgsmlb", 19},
+    second = { "bvwbjplbgvbhsrlpgdmjqwftvncz", 23},
+    third = { "nppdvjthqldpwncqszvftbrmjlhg", 23},
+    fourth = { "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 29},
+    fifth = { "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 26},
+    )]
+    fn part_2_returns_the_number_of_characters_until_the_end_of_the_message_start_marker(
+        input: &str,
+        expected: usize,
+    ) {
+        // Given
+
+        // When
+        let solution = solve_part_2(input).unwrap();
+
+        // Then
+        assert_eq!(solution, Answer::from(expected));
+    }
+}
+
+pub struct Day6;
+
+impl Solver for Day6 {
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn title(&self) -> &'static str {
+        "Tuning Trouble"
+    }
+
+    fn part_one(&self, input: &str) -> Result<Answer> {
+        solve_part_1(input)
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer> {
+        solve_part_2(input)
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_datastream(input)?;
+
+        Ok(())
+    }
+}
+
+pub fn solve_part_1(input_data: &str) -> Result<Answer> {
+    let result = solve(input_data, 4)?;
+
+    Ok(result.into())
+}
+
+pub fn solve_part_2(input_data: &str) -> Result<Answer> {
+    let result = solve(input_data, 14)?;
+
+    Ok(result.into())
+}
+
+/// The characters of the datastream, which are all lowercase letters.
+fn parse_datastream(input_data: &str) -> Result<&[u8]> {
+    let datastream = input_data.trim();
+    if let Some(c) = datastream.chars().find(|c| !c.is_ascii_lowercase()) {
+        bail!("'{c}' is not a character of the datastream!");
+    }
+
+    Ok(datastream.as_bytes())
+}
+
+fn solve(input_data: &str, window_len: usize) -> Result<usize> {
+    for (bytes_before_window, window) in parse_datastream(input_data)?
+        .windows(window_len)
+        .enumerate()
+    {
+        let mut set: HashSet<u8> = HashSet::new();
+        set.extend(window.iter());
+        if set.len() == window_len {
+            return Ok(bytes_before_window + window_len);
+        }
+    }
+
+    Err(eyre!("No marker found with length {window_len}!"))
+}
@@ -1,3 +1,8 @@
+use color_eyre::{
+    eyre::{bail, eyre},
+    Result,
+};
+use indoc::indoc;
 use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::character::complete::{newline, not_line_ending, space1};
@@ -5,6 +10,9 @@ use nom::combinator::{not, opt};
 use nom::multi::{many0, many1};
 use nom::sequence::terminated;
 
+use super::Solver;
+use crate::answer::Answer;
+
 pub type IResult<I, O> = nom::IResult<I, O, nom_supreme::error::ErrorTree<I>>;
 
 #[cfg(test)]
@@ -113,32 +121,7 @@ mod tests {
     #[test]
     fn parse_tree_returns_directory_tree() {
         // Given
-        let input = indoc! {
-            "$ cd /
-            $ ls
-            dir a
-            14848514 b.txt
-            8504156 c.dat
-            dir d
-            $ cd a
-            $ ls
-            dir e
-            29116 f
-            2557 g
-            62596 h.lst
-            $ cd e
-            $ ls
-            584 i
-            $ cd ..
-            $ cd ..
-            $ cd d
-            $ ls
-            4060174 j
-            8033020 d.log
-            5626152 d.ext
-            7214296 k
-            "
-        };
+        let input = EXAMPLE_INPUT;
 
         let expected_tree = &EXAMPLE_TREE;
 
@@ -169,10 +152,79 @@ mod tests {
         // Then
         assert_eq!(actual_size, 48381165);
     }
+
+    #[test]
+    fn solve_part_1_fails_for_output_that_is_not_from_a_terminal_session() {
+        // Given
+        let input = "$ cd /\n$ ls\nthis is not a file\n";
+
+        // When
+        let result = solve_part_1(input);
+
+        // Then
+        assert!(result.is_err());
+    }
 }
 
-pub fn solve_part_1(input_data: &str) -> String {
-    let tree = parse_and_unwrap_tree(input_data);
+const EXAMPLE_INPUT: &str = indoc! {
+    "$ cd /
+    $ ls
+    dir a
+    14848514 b.txt
+    8504156 c.dat
+    dir d
+    $ cd a
+    $ ls
+    dir e
+    29116 f
+    2557 g
+    62596 h.lst
+    $ cd e
+    $ ls
+    584 i
+    $ cd ..
+    $ cd ..
+    $ cd d
+    $ ls
+    4060174 j
+    8033020 d.log
+    5626152 d.ext
+    7214296 k
+    "
+};
+
+pub struct Day7;
+
+impl Solver for Day7 {
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn title(&self) -> &'static str {
+        "No Space Left On Device"
+    }
+
+    fn part_one(&self, input: &str) -> Result<Answer> {
+        solve_part_1(input)
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer> {
+        solve_part_2(input)
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_whole_tree(input)?;
+
+        Ok(())
+    }
+
+    fn example_input(&self) -> Option<&'static str> {
+        Some(EXAMPLE_INPUT)
+    }
+}
+
+pub fn solve_part_1(input_data: &str) -> Result<Answer> {
+    let tree = parse_whole_tree(input_data)?;
 
     let mut size_accumulator = 0u64;
     let mut collect_sizes = |d: &Directory| {
@@ -184,11 +236,11 @@ pub fn solve_part_1(input_data: &str) -> String {
 
     tree.walk_apply(&mut collect_sizes);
 
-    return format!("{size_accumulator}");
+    Ok(size_accumulator.into())
 }
 
-pub fn solve_part_2(input_data: &str) -> String {
-    let tree = parse_and_unwrap_tree(input_data);
+pub fn solve_part_2(input_data: &str) -> Result<Answer> {
+    let tree = parse_whole_tree(input_data)?;
 
     let mut dir_sizes = Vec::new();
 
@@ -204,11 +256,11 @@ pub fn solve_part_2(input_data: &str) -> String {
     let required_free_size = 30_000_000;
     let maximum_allowed_use = drive_size - required_free_size;
     let total_used = tree.size();
-    let minimum_amount_to_free = total_used - maximum_allowed_use;
+    let minimum_amount_to_free = total_used.saturating_sub(maximum_allowed_use);
 
     for s in dir_sizes {
         if s >= minimum_amount_to_free {
-            return format!("{s}");
+            return Ok(s.into());
         }
     }
 
@@ -216,13 +268,14 @@ pub fn solve_part_2(input_data: &str) -> String {
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
-struct File {
-    name: String,
-    size: u64,
+pub struct File {
+    pub name: String,
+    pub size: u64,
 }
 
+/// A directory along with its total size, which is why its contents cannot be changed.
 #[derive(Debug, Clone, Eq, PartialEq)]
-struct Directory {
+pub struct Directory {
     name: String,
     files: Vec<File>,
     subdirectories: Vec<Directory>,
@@ -230,7 +283,7 @@ struct Directory {
 }
 
 impl Directory {
-    fn new(name: &str, files: Vec<File>, subdirectories: Vec<Directory>) -> Self {
+    pub fn new(name: &str, files: Vec<File>, subdirectories: Vec<Directory>) -> Self {
         let total_file_size: u64 = files.iter().map(|f| f.size).sum();
         let total_subdir_size: u64 = subdirectories.iter().map(|d| d.size()).sum();
 
@@ -244,11 +297,25 @@ impl Directory {
         }
     }
 
-    fn size(&self) -> u64 {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn files(&self) -> &[File] {
+        &self.files
+    }
+
+    pub fn subdirectories(&self) -> &[Directory] {
+        &self.subdirectories
+    }
+
+    /// The total size of the files in this directory and all of its subdirectories.
+    pub fn size(&self) -> u64 {
         self.size
     }
 
-    fn walk_apply<F: FnMut(&Self)>(&self, f: &mut F) {
+    /// Calls `f` on this directory and then on all of its subdirectories, depth first.
+    pub fn walk_apply<F: FnMut(&Self)>(&self, f: &mut F) {
         (*f)(self);
 
         for d in self.subdirectories.iter() {
@@ -264,7 +331,7 @@ fn take_rest_of_line(i: &str) -> IResult<&str, &str> {
     Ok((res, rest_of_line))
 }
 
-fn parse_ls_output(i: &str) -> IResult<&str, Vec<File>> {
+pub fn parse_ls_output(i: &str) -> IResult<&str, Vec<File>> {
     fn parse_file(i: &str) -> IResult<&str, Option<File>> {
         let (res, size) = nom::character::complete::u64(i)?;
         let (res, _) = space1(res)?;
@@ -294,7 +361,8 @@ fn parse_ls_output(i: &str) -> IResult<&str, Vec<File>> {
     Ok((res, files))
 }
 
-fn parse_tree(i: &str) -> IResult<&str, Directory> {
+/// Parses the terminal output of exploring a directory and all of its subdirectories.
+pub fn parse_tree(i: &str) -> IResult<&str, Directory> {
     fn parse_cd(i: &str) -> IResult<&str, &str> {
         let _ = not(parse_cd_dot_dot)(i)?;
         let (res, _) = tag("$ cd ")(i)?;
@@ -318,18 +386,13 @@ fn parse_tree(i: &str) -> IResult<&str, Directory> {
     Ok((res, Directory::new(dir_name, files, subdirectories)))
 }
 
-fn parse_and_unwrap_tree(input_data: &str) -> Directory {
-    let outcome = parse_tree(input_data);
-
-    match outcome {
-        Ok((res, tree)) => {
-            assert!(res.is_empty(), r#"Res not empty! Leftovers: "{res}""#);
-
-            tree
-        }
-        Err(e) => {
-            println!("{e:#?}");
-            panic!("Parser failure!");
-        }
+/// Parses the complete terminal output, failing if anything is left over.
+pub fn parse_whole_tree(input_data: &str) -> Result<Directory> {
+    let (res, tree) =
+        parse_tree(input_data).map_err(|e| eyre!("Unable to parse the terminal output: {e}"))?;
+    if !res.is_empty() {
+        bail!(r#"Unable to parse the terminal output from "{res}" on!"#);
     }
+
+    Ok(tree)
 }
@@ -0,0 +1,146 @@
+use color_eyre::Result;
+use indoc::indoc;
+
+use super::Solver;
+use crate::answer::Answer;
+use crate::days::grid::{Direction, Grid, Position};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solve_1_returns_the_number_of_trees_visible_from_outside_the_grid() {
+        // Given
+        // EXAMPLE_INPUT as below
+
+        // When
+        let solution = solve_part_1(EXAMPLE_INPUT).unwrap();
+
+        // Then
+        assert_eq!(solution, Answer::Number(21));
+    }
+
+    #[test]
+    fn scenic_score_multiplies_the_viewing_distances_in_all_directions() {
+        // Given
+        let trees = Grid::parse_digits(EXAMPLE_INPUT).unwrap();
+
+        // When
+        let scores = [
+            Position::new(1, 2),
+            Position::new(3, 2),
+            Position::new(0, 0),
+        ]
+        .map(|position| scenic_score(&trees, position));
+
+        // Then
+        assert_eq!(scores, [4, 8, 0]);
+    }
+
+    #[test]
+    fn solve_2_returns_the_highest_scenic_score() {
+        // Given
+        // EXAMPLE_INPUT as below
+
+        // When
+        let solution = solve_part_2(EXAMPLE_INPUT).unwrap();
+
+        // Then
+        assert_eq!(solution, Answer::Number(8));
+    }
+}
+
+const EXAMPLE_INPUT: &str = indoc! {"
+    30373
+    25512
+    65332
+    33549
+    35390
+"};
+
+pub struct Day8;
+
+impl Solver for Day8 {
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn title(&self) -> &'static str {
+        "Treetop Tree House"
+    }
+
+    fn part_one(&self, input: &str) -> Result<Answer> {
+        solve_part_1(input)
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer> {
+        solve_part_2(input)
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        Grid::parse_digits(input)?;
+
+        Ok(())
+    }
+
+    fn example_input(&self) -> Option<&'static str> {
+        Some(EXAMPLE_INPUT)
+    }
+}
+
+pub fn solve_part_1(input_data: &str) -> Result<Answer> {
+    let trees = Grid::parse_digits(input_data)?;
+
+    let visible_count = trees
+        .positions()
+        .filter(|&position| is_visible(&trees, position))
+        .count();
+
+    Ok(visible_count.into())
+}
+
+pub fn solve_part_2(input_data: &str) -> Result<Answer> {
+    let trees = Grid::parse_digits(input_data)?;
+
+    let highest_score = trees
+        .positions()
+        .map(|position| scenic_score(&trees, position))
+        .max()
+        .unwrap_or_default();
+
+    Ok(highest_score.into())
+}
+
+/// Whether all trees between the tree and the edge of the grid are shorter in any direction.
+fn is_visible(trees: &Grid<u8>, position: Position) -> bool {
+    let height = trees[position];
+
+    Direction::ALL.into_iter().any(|direction| {
+        trees
+            .walk(position, direction)
+            .all(|other| trees[other] < height)
+    })
+}
+
+/// The product of the numbers of trees that can be seen from the tree in each direction.
+///
+/// The view stops at the edge of the grid or at the first tree that is at least as tall.
+fn scenic_score(trees: &Grid<u8>, position: Position) -> usize {
+    let height = trees[position];
+
+    Direction::ALL
+        .into_iter()
+        .map(|direction| {
+            let mut viewing_distance = 0;
+            for other in trees.walk(position, direction) {
+                viewing_distance += 1;
+                if trees[other] >= height {
+                    break;
+                }
+            }
+
+            viewing_distance
+        })
+        .product()
+}
@@ -0,0 +1,280 @@
+use color_eyre::Result;
+use indoc::indoc;
+use nom::branch::alt;
+use nom::character::complete::{char, line_ending};
+use nom::combinator::value;
+use nom::multi::separated_list1;
+use nom::IResult;
+use std::collections::HashSet;
+
+use super::Solver;
+use crate::answer::Answer;
+use crate::days::grid::Direction;
+use crate::days::parse_all;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_motion_returns_direction_and_number_of_steps() {
+        // Given
+        let input = "U 14";
+
+        // When
+        let (res, motion) = parse_motion(input).unwrap();
+
+        // Then
+        assert_eq!(
+            motion,
+            Motion {
+                direction: Direction::Up,
+                steps: 14
+            }
+        );
+        assert!(res.is_empty(), r#"Res not empty! Leftovers: "{res}""#);
+    }
+
+    #[test]
+    fn parse_motions_requires_a_line_break_between_motions() {
+        // Given
+        let input = "U 14R 3\n";
+
+        // When
+        let result = parse_all(parse_motions, input);
+
+        // Then
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Unable to parse the puzzle input from \"R 3\n\" on!"
+        );
+    }
+
+    #[test]
+    fn steps_returns_the_knot_positions_after_every_single_step() {
+        // Given
+        let motions = [
+            Motion {
+                direction: Direction::Right,
+                steps: 2,
+            },
+            Motion {
+                direction: Direction::Up,
+                steps: 2,
+            },
+        ];
+
+        // When
+        let states: Vec<_> = Rope::new(2)
+            .steps(&motions)
+            .map(|rope| rope.knots().to_vec())
+            .collect();
+
+        // Then
+        assert_eq!(
+            states,
+            vec![
+                vec![Point::new(1, 0), Point::new(0, 0)],
+                vec![Point::new(2, 0), Point::new(1, 0)],
+                vec![Point::new(2, 1), Point::new(1, 0)],
+                vec![Point::new(2, 2), Point::new(2, 1)],
+            ]
+        );
+    }
+
+    #[test]
+    fn solve_1_returns_the_number_of_positions_visited_by_the_tail_of_a_short_rope() {
+        // Given
+        // EXAMPLE_INPUT as below
+
+        // When
+        let solution = solve_part_1(EXAMPLE_INPUT).unwrap();
+
+        // Then
+        assert_eq!(solution, Answer::Number(13));
+    }
+
+    #[test]
+    fn solve_2_returns_the_number_of_positions_visited_by_the_tail_of_a_long_rope() {
+        // Given
+        let input = indoc! {"
+            R 5
+            U 8
+            L 8
+            D 3
+            R 17
+            D 10
+            L 25
+            U 20
+        "};
+
+        // When
+        let solutions = [EXAMPLE_INPUT, input].map(|input| solve_part_2(input).unwrap());
+
+        // Then
+        assert_eq!(solutions, [Answer::Number(1), Answer::Number(36)]);
+    }
+}
+
+const EXAMPLE_INPUT: &str = indoc! {"
+    R 4
+    U 4
+    L 3
+    D 1
+    R 4
+    D 1
+    L 5
+    R 2
+"};
+
+pub struct Day9;
+
+impl Solver for Day9 {
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn title(&self) -> &'static str {
+        "Rope Bridge"
+    }
+
+    fn part_one(&self, input: &str) -> Result<Answer> {
+        solve_part_1(input)
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer> {
+        solve_part_2(input)
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_all(parse_motions, input)?;
+
+        Ok(())
+    }
+
+    fn example_input(&self) -> Option<&'static str> {
+        Some(EXAMPLE_INPUT)
+    }
+}
+
+pub fn solve_part_1(input_data: &str) -> Result<Answer> {
+    let motions = parse_all(parse_motions, input_data)?;
+
+    Ok(visited_tail_positions(2, &motions).len().into())
+}
+
+pub fn solve_part_2(input_data: &str) -> Result<Answer> {
+    let motions = parse_all(parse_motions, input_data)?;
+
+    Ok(visited_tail_positions(10, &motions).len().into())
+}
+
+/// All positions the tail of a rope with `knot_count` knots visits, including where it starts.
+pub fn visited_tail_positions(knot_count: usize, motions: &[Motion]) -> HashSet<Point> {
+    let rope = Rope::new(knot_count);
+
+    let mut visited = HashSet::from([rope.tail()]);
+    visited.extend(rope.steps(motions).map(|rope| rope.tail()));
+
+    visited
+}
+
+/// A position on the plane, with `y` growing upwards.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    fn moved(self, direction: Direction) -> Self {
+        let (row_offset, column_offset) = direction.offset();
+
+        // Rows grow downwards.
+        Self::new(self.x + column_offset as i32, self.y - row_offset as i32)
+    }
+
+    /// Where a knot at this position moves to after the knot in front of it moved to `leader`.
+    fn following(self, leader: Point) -> Self {
+        let (dx, dy) = (leader.x - self.x, leader.y - self.y);
+        if dx.abs() <= 1 && dy.abs() <= 1 {
+            return self;
+        }
+
+        Self::new(self.x + dx.signum(), self.y + dy.signum())
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Motion {
+    pub direction: Direction,
+    pub steps: u32,
+}
+
+/// A rope whose knots all start at the origin.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rope {
+    /// The positions of the knots, from the head to the tail.
+    knots: Vec<Point>,
+}
+
+impl Rope {
+    pub fn new(knot_count: usize) -> Self {
+        assert!(knot_count > 0, "A rope needs at least one knot!");
+
+        Self {
+            knots: vec![Point::new(0, 0); knot_count],
+        }
+    }
+
+    /// The positions of the knots, from the head to the tail.
+    pub fn knots(&self) -> &[Point] {
+        &self.knots
+    }
+
+    pub fn tail(&self) -> Point {
+        self.knots[self.knots.len() - 1]
+    }
+
+    /// Moves the head a single step and lets all other knots follow.
+    pub fn step(&mut self, direction: Direction) {
+        self.knots[0] = self.knots[0].moved(direction);
+
+        for index in 1..self.knots.len() {
+            self.knots[index] = self.knots[index].following(self.knots[index - 1]);
+        }
+    }
+
+    /// The state of the rope after every single step of the motions.
+    pub fn steps<'a>(mut self, motions: &'a [Motion]) -> impl Iterator<Item = Rope> + 'a {
+        motions
+            .iter()
+            .flat_map(|motion| (0..motion.steps).map(|_| motion.direction))
+            .map(move |direction| {
+                self.step(direction);
+                self.clone()
+            })
+    }
+}
+
+fn parse_motion(i: &str) -> IResult<&str, Motion> {
+    let (res, direction) = alt((
+        value(Direction::Up, char('U')),
+        value(Direction::Down, char('D')),
+        value(Direction::Left, char('L')),
+        value(Direction::Right, char('R')),
+    ))(i)?;
+    let (res, _) = char(' ')(res)?;
+    let (res, steps) = nom::character::complete::u32(res)?;
+
+    Ok((res, Motion { direction, steps }))
+}
+
+pub fn parse_motions(i: &str) -> IResult<&str, Vec<Motion>> {
+    separated_list1(line_ending, parse_motion)(i)
+}
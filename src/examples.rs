@@ -0,0 +1,308 @@
+//! Finds the examples and their expected answers in a puzzle description rendered by
+//! `puzzle_page::to_markdown`.
+
+use color_eyre::{eyre::bail, Result};
+
+use crate::answer::Answer;
+use crate::days::Solver;
+use crate::puzzle_page::PART_TWO_HEADING;
+use crate::{catch_panic, Part};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::year_2022::day_6::Day6;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn extract_uses_the_example_of_part_one_and_the_last_emphasised_answer_of_each_part() {
+        // Given
+        let puzzle = indoc! {"
+            ## --- Day 1: Calorie Counting ---
+
+            For example:
+
+            ```
+            1000
+            2000
+
+            3000
+            ```
+
+            - The first Elf is carrying a total of *`3000`* Calories.
+
+            In the example above, this is *`3000`* (carried by the *first* Elf).
+
+            ## --- Part Two ---
+
+            The sum of the Calories carried by these elves is *`6000`*.
+        "};
+
+        // When
+        let examples = extract(puzzle);
+
+        // Then
+        assert_eq!(
+            examples,
+            vec![
+                Example {
+                    part: Part::One,
+                    input: "1000\n2000\n\n3000\n".to_owned(),
+                    expected: Answer::Number(3000),
+                },
+                Example {
+                    part: Part::Two,
+                    input: "1000\n2000\n\n3000\n".to_owned(),
+                    expected: Answer::Number(6000),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn extract_turns_list_items_with_input_and_answer_into_additional_examples() {
+        // Given
+        let puzzle = indoc! {"
+            ## --- Day 6: Tuning Trouble ---
+
+            ```
+            mjqjpqmgbljsphdztnvjfqwrcgsmlb
+            ```
+
+            The first marker is complete after character *`7`*.
+
+            - `bvwbjplbgvbhsrlpgdmjqwftvncz`: first marker after character *`5`*
+            - `nppdvjthqldpwncqszvftbrmjlhg`: first marker after character *`6`*
+
+            ## --- Part Two ---
+
+            - `mjqjpqmgbljsphdztnvjfqwrcgsmlb`: first marker after character *`19`*
+        "};
+
+        // When
+        let examples = extract(puzzle);
+
+        // Then
+        let examples: Vec<_> = examples
+            .iter()
+            .map(|e| (e.part, e.input.as_str(), e.expected.clone()))
+            .collect();
+        assert_eq!(
+            examples,
+            vec![
+                (
+                    Part::One,
+                    "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n",
+                    Answer::Number(7)
+                ),
+                (Part::One, "bvwbjplbgvbhsrlpgdmjqwftvncz", Answer::Number(5)),
+                (Part::One, "nppdvjthqldpwncqszvftbrmjlhg", Answer::Number(6)),
+                (
+                    Part::Two,
+                    "mjqjpqmgbljsphdztnvjfqwrcgsmlb",
+                    Answer::Number(19)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn check_fails_for_answers_other_than_the_expected_one() {
+        // Given
+        let example = Example {
+            part: Part::One,
+            input: "bvwbjplbgvbhsrlpgdmjqwftvncz".to_owned(),
+            expected: Answer::Number(6),
+        };
+
+        // When
+        let result = check(&Day6, &example);
+
+        // Then
+        assert_eq!(result.unwrap_err().to_string(), "expected 6, got 5");
+    }
+
+    #[test]
+    fn check_all_checks_every_example_of_the_puzzle() {
+        // Given
+        let puzzle = indoc! {"
+            ## --- Day 6: Tuning Trouble ---
+
+            - `bvwbjplbgvbhsrlpgdmjqwftvncz`: first marker after character *`5`*
+            - `nppdvjthqldpwncqszvftbrmjlhg`: first marker after character *`7`*
+        "};
+
+        // When
+        let checked = check_all(&Day6, puzzle).unwrap();
+
+        // Then
+        let results: Vec<_> = checked
+            .iter()
+            .map(|checked| match &checked.result {
+                Ok(answer) => answer.to_string(),
+                Err(e) => e.to_string(),
+            })
+            .collect();
+        assert_eq!(results, vec!["5", "expected 7, got 6"]);
+    }
+
+    #[test]
+    fn check_all_fails_for_puzzles_without_examples() {
+        // Given
+        let puzzle = "## --- Day 6: Tuning Trouble ---\n";
+
+        // When
+        let result = check_all(&Day6, puzzle);
+
+        // Then
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "Found no examples in the puzzle description of day 6!"
+        );
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub part: Part,
+    pub input: String,
+    pub expected: Answer,
+}
+
+/// Solves the example, failing if the solver fails, panics or does not return the expected
+/// answer.
+pub fn check(solver: &dyn Solver, example: &Example) -> Result<Answer> {
+    let actual = catch_panic(|| example.part.solve(solver, &example.input))??;
+    if actual != example.expected {
+        bail!("expected {}, got {actual}", example.expected);
+    }
+
+    Ok(actual)
+}
+
+/// An example along with the answer of the solver, or why it did not match the expected one.
+pub struct CheckedExample {
+    pub example: Example,
+    pub result: Result<Answer>,
+}
+
+/// Extracts the examples from the Markdown of a puzzle description and checks each of them,
+/// failing if there are none.
+pub fn check_all(solver: &dyn Solver, puzzle: &str) -> Result<Vec<CheckedExample>> {
+    let examples = extract(puzzle);
+    if examples.is_empty() {
+        bail!(
+            "Found no examples in the puzzle description of day {}!",
+            solver.day()
+        );
+    }
+
+    Ok(examples
+        .into_iter()
+        .map(|example| CheckedExample {
+            result: check(solver, &example),
+            example,
+        })
+        .collect())
+}
+
+/// Extracts the examples of both parts from the Markdown of a puzzle description.
+///
+/// The main example of a part is the first code block of its description (part two falls back
+/// to the one of part one), expecting the last emphasised code span that is not part of a list
+/// item example. List items of the form ``- `input`: ... *`answer`*`` are examples of their own.
+pub fn extract(puzzle: &str) -> Vec<Example> {
+    let (part_one, part_two) = match puzzle.find(PART_TWO_HEADING) {
+        Some(split) => (&puzzle[..split], Some(&puzzle[split..])),
+        None => (puzzle, None),
+    };
+
+    let part_one_input = first_code_block(part_one);
+    let mut examples = section_examples(Part::One, part_one, part_one_input.clone());
+
+    if let Some(part_two) = part_two {
+        let part_two_input = first_code_block(part_two).or(part_one_input);
+        examples.extend(section_examples(Part::Two, part_two, part_two_input));
+    }
+
+    examples
+}
+
+fn section_examples(part: Part, section: &str, main_input: Option<String>) -> Vec<Example> {
+    let mut examples = Vec::new();
+    let mut list_examples = Vec::new();
+    let mut main_expected = None;
+    let mut in_code_block = false;
+
+    for line in section.lines() {
+        if line.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+
+        match list_example(line) {
+            Some(input) => {
+                if let Some(expected) = emphasised_code(line).pop() {
+                    list_examples.push(Example {
+                        part,
+                        input: input.to_owned(),
+                        expected: Answer::parse(expected),
+                    });
+                }
+            }
+            None => {
+                if let Some(expected) = emphasised_code(line).pop() {
+                    main_expected = Some(Answer::parse(expected));
+                }
+            }
+        }
+    }
+
+    if let (Some(input), Some(expected)) = (main_input, main_expected) {
+        examples.push(Example {
+            part,
+            input,
+            expected,
+        });
+    }
+    examples.extend(list_examples);
+
+    examples
+}
+
+fn first_code_block(section: &str) -> Option<String> {
+    let start = section.find("```\n")? + "```\n".len();
+    let length = section[start..].find("```")?;
+
+    Some(section[start..start + length].to_owned())
+}
+
+/// The input of a list item like ``- `input`: explanation``.
+fn list_example(line: &str) -> Option<&str> {
+    let rest = line.strip_prefix("- `")?;
+    let (input, rest) = rest.split_once('`')?;
+
+    rest.starts_with(':').then_some(input)
+}
+
+/// The contents of all emphasised code spans (*`like this`*) of the line.
+fn emphasised_code(line: &str) -> Vec<&str> {
+    let mut spans = Vec::new();
+    let mut rest = line;
+
+    while let Some(start) = rest.find("*`") {
+        let span_start = start + "*`".len();
+        let Some(length) = rest[span_start..].find("`*") else {
+            break;
+        };
+
+        spans.push(&rest[span_start..span_start + length]);
+        rest = &rest[span_start + length + "`*".len()..];
+    }
+
+    spans
+}
@@ -1,21 +1,450 @@
+use std::cell::Cell;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use reqwest::Url;
+use reqwest::blocking::Response;
+use reqwest::{StatusCode, Url};
 
-pub fn fetch(advent_day: u8, session_id: &str) -> String {
-    let puzzle_input_url = format!("https://adventofcode.com/2022/day/{advent_day}/input");
-    let base_url = "https://adventofcode.com/2022".parse::<Url>().unwrap();
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
 
-    let cookie_jar = reqwest::cookie::Jar::default();
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{StubServer, TempDir};
+    use std::time::Instant;
+    use yare::parameterized;
 
-    let session_cookie = format!("session={session_id}");
-    cookie_jar.add_cookie_str(&session_cookie, &base_url);
+    fn test_settings() -> ClientSettings {
+        ClientSettings {
+            user_agent: "test-agent".to_owned(),
+            min_request_interval: Duration::ZERO,
+            last_request_file: None,
+            max_retries: 2,
+            initial_backoff: Duration::from_millis(1),
+        }
+    }
 
-    let client = reqwest::blocking::ClientBuilder::new()
-        .cookie_provider(Arc::new(cookie_jar))
-        .build()
-        .unwrap();
-    let resp = client.get(puzzle_input_url).send().unwrap();
-    let resp = resp.error_for_status().unwrap();
-    resp.text().unwrap()
+    #[test]
+    fn fetch_downloads_the_input_of_the_given_day_with_the_session_cookie() {
+        // Given
+        let server = StubServer::serve(vec![StubServer::ok("1000\n2000\n")]);
+        let client = Client::new(&server.base_url(), "abc123", test_settings()).unwrap();
+
+        // When
+        let input = client.fetch(2022, 1).unwrap();
+
+        // Then
+        assert_eq!(input, "1000\n2000\n");
+        let requests = server.requests();
+        assert!(requests[0].starts_with("GET /2022/day/1/input "));
+        assert!(requests[0].contains("cookie: session=abc123"));
+        assert!(requests[0].contains("user-agent: test-agent"));
+    }
+
+    #[test]
+    fn fetch_puzzle_downloads_the_puzzle_page_of_the_given_day() {
+        // Given
+        let server = StubServer::serve(vec![StubServer::ok("<article>Day 3</article>")]);
+        let client = Client::new(&server.base_url(), "abc123", test_settings()).unwrap();
+
+        // When
+        let page = client.fetch_puzzle(2015, 3).unwrap();
+
+        // Then
+        assert_eq!(page, "<article>Day 3</article>");
+        assert!(server.requests()[0].starts_with("GET /2015/day/3 "));
+    }
+
+    #[test]
+    fn fetch_retries_server_errors() {
+        // Given
+        let server = StubServer::serve(vec![
+            StubServer::status(502, "Bad Gateway"),
+            StubServer::status(503, "Service Unavailable"),
+            StubServer::ok("1000\n"),
+        ]);
+        let client = Client::new(&server.base_url(), "abc123", test_settings()).unwrap();
+
+        // When
+        let input = client.fetch(2022, 1).unwrap();
+
+        // Then
+        assert_eq!(input, "1000\n");
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[test]
+    fn fetch_gives_up_after_the_maximum_number_of_retries() {
+        // Given
+        let server = StubServer::serve(vec![
+            StubServer::status(500, "Oops"),
+            StubServer::status(500, "Oops"),
+            StubServer::status(500, "Oops"),
+        ]);
+        let client = Client::new(&server.base_url(), "abc123", test_settings()).unwrap();
+
+        // When
+        let error = client.fetch(2022, 1).unwrap_err();
+
+        // Then
+        assert!(matches!(error, FetchError::ServerError(500)));
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[test]
+    fn fetch_waits_for_the_minimum_interval_since_the_last_request_of_an_earlier_client() {
+        // Given
+        let temp_dir = TempDir::new();
+        let last_request_file = temp_dir.path().join("last_request");
+        let settings = ClientSettings {
+            min_request_interval: Duration::from_millis(300),
+            last_request_file: Some(last_request_file),
+            ..test_settings()
+        };
+        let server = StubServer::serve(vec![StubServer::ok("1"), StubServer::ok("2")]);
+        Client::new(&server.base_url(), "abc123", settings.clone())
+            .unwrap()
+            .fetch(2022, 1)
+            .unwrap();
+        let start = Instant::now();
+
+        // When
+        Client::new(&server.base_url(), "abc123", settings)
+            .unwrap()
+            .fetch(2022, 2)
+            .unwrap();
+
+        // Then
+        assert!(start.elapsed() >= Duration::from_millis(250));
+    }
+
+    #[test]
+    fn submit_posts_the_answer_for_the_given_level() {
+        // Given
+        let server = StubServer::serve(vec![StubServer::ok("<article>Yay</article>")]);
+        let client = Client::new(&server.base_url(), "abc123", test_settings()).unwrap();
+
+        // When
+        let response = client.submit(2022, 7, 2, "24933642").unwrap();
+
+        // Then
+        assert_eq!(response, "<article>Yay</article>");
+        let requests = server.requests();
+        assert!(requests[0].starts_with("POST /2022/day/7/answer "));
+        assert!(requests[0].contains("cookie: session=abc123"));
+        assert!(requests[0].ends_with("level=2&answer=24933642"));
+    }
+
+    #[parameterized(
+        bad_request = { 400, |error| matches!(error, FetchError::SessionExpired) },
+        not_found = { 404, |error| matches!(error, FetchError::NotUnlocked(2022, 25)) },
+        internal_server_error = { 500, |error| matches!(error, FetchError::ServerError(500)) },
+        service_unavailable = { 503, |error| matches!(error, FetchError::ServerError(503)) },
+        forbidden = { 403, |error| matches!(error, FetchError::UnexpectedStatus(403)) },
+    )]
+    fn fetch_maps_error_statuses_to_fetch_errors(
+        status: u16,
+        is_expected_error: fn(&FetchError) -> bool,
+    ) {
+        // Given
+        let server = StubServer::serve(vec![StubServer::status(status, "Nope")]);
+        let settings = ClientSettings {
+            max_retries: 0,
+            ..test_settings()
+        };
+        let client = Client::new(&server.base_url(), "abc123", settings).unwrap();
+
+        // When
+        let error = client.fetch(2022, 25).unwrap_err();
+
+        // Then
+        assert!(is_expected_error(&error), "Unexpected error: {error:?}");
+    }
+
+    #[test]
+    fn fetch_reports_network_failures() {
+        // Given
+        let unreachable_url = "http://127.0.0.1:1".parse().unwrap();
+        let client = Client::new(&unreachable_url, "abc123", test_settings()).unwrap();
+
+        // When
+        let error = client.fetch(2022, 1).unwrap_err();
+
+        // Then
+        assert!(matches!(error, FetchError::Network(_)));
+    }
+}
+
+/// Everything that can go wrong while talking to the aoc website.
+#[derive(Debug)]
+pub enum FetchError {
+    /// The website could not be reached, or the response could not be read.
+    Network(reqwest::Error),
+    /// The website rejected the session cookie.
+    SessionExpired,
+    /// The puzzle of the given day is not unlocked yet.
+    NotUnlocked(u16, u8),
+    ServerError(u16),
+    UnexpectedStatus(u16),
+    /// The time of the last request could not be read from or written to disk.
+    Throttle(PathBuf, io::Error),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::Network(_) => write!(
+                f,
+                "Unable to talk to the aoc website! Check your internet connection."
+            ),
+            FetchError::SessionExpired => write!(
+                f,
+                "The aoc website rejected the session id, your session cookie has probably expired! \
+                 Log in again and pass the new value of the \"session\" cookie."
+            ),
+            FetchError::NotUnlocked(year, advent_day) => write!(
+                f,
+                "The puzzle of day {advent_day} of {year} is not unlocked yet! Try again once it is \
+                 released."
+            ),
+            FetchError::ServerError(status) => write!(
+                f,
+                "The aoc website failed with status {status}! Try again in a few minutes."
+            ),
+            FetchError::UnexpectedStatus(status) => {
+                write!(f, "The aoc website answered with unexpected status {status}!")
+            }
+            FetchError::Throttle(path, _) => write!(
+                f,
+                "Unable to keep track of the last request to the aoc website in {}!",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl Error for FetchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FetchError::Network(e) => Some(e),
+            FetchError::Throttle(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for FetchError {
+    fn from(e: reqwest::Error) -> Self {
+        FetchError::Network(e)
+    }
+}
+
+/// How the client treats the website, following the aoc guidelines for automated tools.
+#[derive(Debug, Clone)]
+pub struct ClientSettings {
+    /// Identifies the tool and a way to contact its user to the operators of the website.
+    pub user_agent: String,
+    /// The minimum time between two requests, even across runs of the tool.
+    pub min_request_interval: Duration,
+    /// Where to remember the time of the last request. Without it, only requests of the same
+    /// client are spaced out.
+    pub last_request_file: Option<PathBuf>,
+    /// How often to retry a download that failed with a server error.
+    pub max_retries: u32,
+    /// The wait before the first retry, doubling for every further one.
+    pub initial_backoff: Duration,
+}
+
+impl Default for ClientSettings {
+    fn default() -> Self {
+        Self {
+            user_agent: default_user_agent(None),
+            min_request_interval: Duration::from_secs(5),
+            last_request_file: None,
+            max_retries: 3,
+            initial_backoff: Duration::from_secs(1),
+        }
+    }
+}
+
+/// The User-Agent to send unless the user configured another one.
+pub fn default_user_agent(contact: Option<&str>) -> String {
+    let user_agent = format!(
+        "{}/{} (+https://github.com/AndreasKarg/advent-of-code-2022",
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION")
+    );
+
+    match contact {
+        Some(contact) => format!("{user_agent}; {contact})"),
+        None => format!("{user_agent})"),
+    }
+}
+
+/// Talks to the aoc website (or a stand-in for it) on behalf of a single session.
+pub struct Client {
+    base_url: String,
+    http: reqwest::blocking::Client,
+    settings: ClientSettings,
+    last_request: Cell<Option<SystemTime>>,
+}
+
+impl Client {
+    pub fn new(
+        base_url: &Url,
+        session_id: &str,
+        settings: ClientSettings,
+    ) -> Result<Self, FetchError> {
+        let cookie_jar = reqwest::cookie::Jar::default();
+
+        let session_cookie = format!("session={session_id}");
+        cookie_jar.add_cookie_str(&session_cookie, base_url);
+
+        let http = reqwest::blocking::ClientBuilder::new()
+            .cookie_provider(Arc::new(cookie_jar))
+            .user_agent(&settings.user_agent)
+            .build()?;
+
+        Ok(Self {
+            base_url: base_url.as_str().trim_end_matches('/').to_owned(),
+            http,
+            settings,
+            last_request: Cell::new(None),
+        })
+    }
+
+    pub fn fetch(&self, year: u16, advent_day: u8) -> Result<String, FetchError> {
+        let puzzle_input_url = format!("{}/{year}/day/{advent_day}/input", self.base_url);
+
+        let resp = self.get(year, advent_day, &puzzle_input_url)?;
+
+        Ok(resp.text()?)
+    }
+
+    /// Downloads the HTML page with the puzzle description of the day.
+    pub fn fetch_puzzle(&self, year: u16, advent_day: u8) -> Result<String, FetchError> {
+        let puzzle_url = format!("{}/{year}/day/{advent_day}", self.base_url);
+
+        let resp = self.get(year, advent_day, &puzzle_url)?;
+
+        Ok(resp.text()?)
+    }
+
+    /// Downloads `url`, retrying with exponential backoff as long as the server fails.
+    fn get(&self, year: u16, advent_day: u8, url: &str) -> Result<Response, FetchError> {
+        let mut backoff = self.settings.initial_backoff;
+        let mut retries = 0;
+
+        loop {
+            self.wait_for_turn()?;
+            let resp = self.http.get(url).send()?;
+
+            match check_status(year, advent_day, resp) {
+                Err(FetchError::ServerError(_)) if retries < self.settings.max_retries => {
+                    thread::sleep(backoff);
+                    backoff *= 2;
+                    retries += 1;
+                }
+                outcome => return outcome,
+            }
+        }
+    }
+
+    /// Sleeps until the minimum interval since the last request has passed, then records the
+    /// current time as the time of the last request.
+    fn wait_for_turn(&self) -> Result<(), FetchError> {
+        let last_request = match &self.settings.last_request_file {
+            Some(path) => read_last_request(path)?,
+            None => self.last_request.get(),
+        };
+
+        if let Some(last_request) = last_request {
+            let elapsed = last_request.elapsed().unwrap_or_default();
+            if elapsed < self.settings.min_request_interval {
+                thread::sleep(self.settings.min_request_interval - elapsed);
+            }
+        }
+
+        let now = SystemTime::now();
+        self.last_request.set(Some(now));
+        if let Some(path) = &self.settings.last_request_file {
+            write_last_request(path, now)?;
+        }
+
+        Ok(())
+    }
+
+    /// Submits `answer` for part `level` of the day and returns the body of the response page.
+    ///
+    /// Unlike downloads, submissions are never retried, so that a flaky server cannot make us
+    /// submit the same answer twice.
+    pub fn submit(
+        &self,
+        year: u16,
+        advent_day: u8,
+        level: u8,
+        answer: &str,
+    ) -> Result<String, FetchError> {
+        let answer_url = format!("{}/{year}/day/{advent_day}/answer", self.base_url);
+        let level = level.to_string();
+
+        self.wait_for_turn()?;
+        let resp = self
+            .http
+            .post(answer_url)
+            .form(&[("level", level.as_str()), ("answer", answer)])
+            .send()?;
+        let resp = check_status(year, advent_day, resp)?;
+
+        Ok(resp.text()?)
+    }
+}
+
+fn read_last_request(path: &Path) -> Result<Option<SystemTime>, FetchError> {
+    let millis = match fs::read_to_string(path) {
+        Ok(millis) => millis,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(FetchError::Throttle(path.to_owned(), e)),
+    };
+
+    // A corrupted file must not lock us out, so it counts as "no request yet".
+    Ok(millis
+        .trim()
+        .parse()
+        .ok()
+        .map(|millis| UNIX_EPOCH + Duration::from_millis(millis)))
+}
+
+fn write_last_request(path: &Path, time: SystemTime) -> Result<(), FetchError> {
+    let millis = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| FetchError::Throttle(path.to_owned(), e))?;
+    }
+    fs::write(path, millis.to_string()).map_err(|e| FetchError::Throttle(path.to_owned(), e))
+}
+
+fn check_status(year: u16, advent_day: u8, resp: Response) -> Result<Response, FetchError> {
+    let status = resp.status();
+
+    if status.is_success() {
+        Ok(resp)
+    } else if status == StatusCode::BAD_REQUEST {
+        Err(FetchError::SessionExpired)
+    } else if status == StatusCode::NOT_FOUND {
+        Err(FetchError::NotUnlocked(year, advent_day))
+    } else if status.is_server_error() {
+        Err(FetchError::ServerError(status.as_u16()))
+    } else {
+        Err(FetchError::UnexpectedStatus(status.as_u16()))
+    }
 }
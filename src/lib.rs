@@ -0,0 +1,133 @@
+//! Solvers for the puzzles of Advent of Code, along with the client and cache for the inputs,
+//! puzzle descriptions and answers of the aoc website.
+//!
+//! The `advent-of-code-2022` binary is a command line interface over this library.
+
+// The day modules keep their tests at the top of the file.
+#![allow(clippy::items_after_test_module)]
+
+use clap::ValueEnum;
+use color_eyre::{eyre::eyre, Result};
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+pub mod answer;
+pub mod bench;
+pub mod cache;
+pub mod days;
+pub mod examples;
+pub mod input_fetcher;
+pub mod puzzle_page;
+pub mod report;
+pub mod scaffold;
+pub mod submission;
+#[cfg(test)]
+mod test_support;
+pub mod verify;
+pub mod watch;
+
+use answer::Answer;
+use days::Solver;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn catch_panic_turns_panics_on_several_threads_into_errors() {
+        // Given
+        let threads: Vec<_> = (0..4)
+            .map(|idx| thread::spawn(move || catch_panic(|| panic!("thread {idx}"))))
+            .collect();
+
+        // When
+        let messages: Vec<_> = threads
+            .into_iter()
+            .map(|thread| thread.join().unwrap().unwrap_err().to_string())
+            .collect();
+
+        // Then
+        assert_eq!(
+            messages,
+            vec![
+                "Panicked: thread 0",
+                "Panicked: thread 1",
+                "Panicked: thread 2",
+                "Panicked: thread 3"
+            ]
+        );
+    }
+
+    #[test]
+    fn catch_panic_returns_the_result_of_functions_that_do_not_panic() {
+        // When
+        let result = catch_panic(|| 42);
+
+        // Then
+        assert_eq!(result.unwrap(), 42);
+    }
+}
+
+#[derive(ValueEnum, Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// The number the aoc website uses to identify this part.
+    pub fn level(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn solve(self, solver: &dyn Solver, input: &str) -> Result<Answer> {
+        match self {
+            Part::One => solver.part_one(input),
+            Part::Two => solver.part_two(input),
+        }
+    }
+}
+
+thread_local! {
+    /// Whether the current thread runs inside [`catch_panic`], whose panics are not printed.
+    static CATCHING_PANICS: Cell<bool> = const { Cell::new(false) };
+}
+
+static SILENCE_CAUGHT_PANICS: Once = Once::new();
+
+/// Runs `f`, turning a panic into an error instead of unwinding further.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T> {
+    // The panic hook is global, so it is wrapped once instead of being swapped on every call,
+    // which would race with other threads.
+    SILENCE_CAUGHT_PANICS.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CATCHING_PANICS.with(Cell::get) {
+                default_hook(info);
+            }
+        }));
+    });
+
+    let was_catching = CATCHING_PANICS.with(|catching| catching.replace(true));
+    let outcome = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING_PANICS.with(|catching| catching.set(was_catching));
+
+    outcome.map_err(|payload| {
+        let message = if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "unknown panic".to_owned()
+        };
+
+        eyre!("Panicked: {message}")
+    })
+}
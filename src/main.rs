@@ -1,23 +1,249 @@
-#![feature(iter_array_chunks)]
+use clap::{Parser, Subcommand};
+use color_eyre::{
+    eyre::{bail, eyre},
+    Result,
+};
+use itertools::Itertools;
+use reqwest::Url;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-use clap::{Parser, ValueEnum};
-use color_eyre::{eyre::Context, Result};
-use std::collections::hash_map::DefaultHasher;
-use std::fs::{create_dir_all, read, write};
-use std::hash::{Hash, Hasher};
-
-mod days;
-mod input_fetcher;
-
-#[derive(ValueEnum, Clone, PartialEq, Eq, Debug)]
-enum Part {
-    One,
-    Two,
-}
+use advent_of_code_2022::cache::{self, InputSelection, LoadedInput, SessionCache};
+use advent_of_code_2022::days::{self, Solver};
+use advent_of_code_2022::report::{self, InputSource, OutputFormat, RunReport};
+use advent_of_code_2022::watch::{self, Watch, WatchedInput};
+use advent_of_code_2022::{
+    bench, catch_panic, examples, input_fetcher, scaffold, submission, verify, Part,
+};
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
+    #[command(subcommand)]
+    command: Command,
+
+    /// The year of the puzzles
+    #[arg(long, global = true, env = "AOC_YEAR", default_value_t = DEFAULT_YEAR)]
+    year: u16,
+
+    #[command(flatten)]
+    remote: RemoteArgs,
+}
+
+const DEFAULT_YEAR: u16 = 2022;
+
+/// How to talk to the aoc website.
+#[derive(clap::Args, Debug)]
+struct RemoteArgs {
+    /// The URL of the aoc website, e.g. to point at a local stand-in
+    #[arg(long, global = true, env = "AOC_BASE_URL", default_value = input_fetcher::DEFAULT_BASE_URL)]
+    base_url: Url,
+
+    /// Contact info (e.g. an email address) to include in the User-Agent header
+    #[arg(long, global = true, env = "AOC_CONTACT")]
+    contact: Option<String>,
+
+    /// The User-Agent header to send instead of the default one
+    #[arg(long, global = true, env = "AOC_USER_AGENT")]
+    user_agent: Option<String>,
+
+    /// The minimum number of seconds between two requests to the aoc website
+    #[arg(long, global = true, value_name = "SECONDS", default_value_t = 5)]
+    min_request_interval: u64,
+}
+
+impl RemoteArgs {
+    fn client(&self, session_id: &str) -> Result<input_fetcher::Client> {
+        let user_agent = match &self.user_agent {
+            Some(user_agent) => user_agent.clone(),
+            None => input_fetcher::default_user_agent(self.contact.as_deref()),
+        };
+
+        let settings = input_fetcher::ClientSettings {
+            user_agent,
+            min_request_interval: Duration::from_secs(self.min_request_interval),
+            last_request_file: Some(cache::cache_dir().join("last_request")),
+            ..Default::default()
+        };
+
+        Ok(input_fetcher::Client::new(
+            &self.base_url,
+            session_id,
+            settings,
+        )?)
+    }
+
+    /// The arguments to pass on to another invocation of this tool to talk to the website alike.
+    fn to_args(&self) -> Vec<String> {
+        let mut args = vec![
+            "--base-url".to_owned(),
+            self.base_url.to_string(),
+            "--min-request-interval".to_owned(),
+            self.min_request_interval.to_string(),
+        ];
+        if let Some(contact) = &self.contact {
+            args.extend(["--contact".to_owned(), contact.clone()]);
+        }
+        if let Some(user_agent) = &self.user_agent {
+            args.extend(["--user-agent".to_owned(), user_agent.clone()]);
+        }
+
+        args
+    }
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Solve one of the two tasks of an advent day
+    Solve(SolveArgs),
+
+    /// Solve one of the two tasks of an advent day and submit the solution to the aoc website
+    Submit {
+        /// The advent day to solve
+        advent_day: u8,
+
+        /// Which of the two tasks of the day to solve
+        #[arg(value_enum)]
+        part: Part,
+
+        /// The value of the "session" cookie on the aoc website
+        #[arg(short, long, env = "AOC_SESSION_ID")]
+        session_id: String,
+    },
+
+    /// Re-run the tests and the solver of an advent day whenever its source or input file changes
+    Watch {
+        /// The advent day to solve
+        advent_day: u8,
+
+        /// Which of the two tasks of the day to solve
+        #[arg(value_enum)]
+        part: Part,
+
+        #[command(flatten)]
+        input: InputArgs,
+    },
+
+    /// List all advent days that have a solver
+    List,
+
+    /// Solve both tasks of every advent day that has a solver and print a summary
+    RunAll {
+        /// The value of the "session" cookie on the aoc website
+        #[arg(short, long, env = "AOC_SESSION_ID")]
+        session_id: String,
+
+        /// How to print the solutions
+        #[arg(long, value_enum, default_value_t)]
+        format: OutputFormat,
+    },
+
+    /// Solve all tasks with an accepted answer again and fail if any solution changed
+    Verify {
+        /// The value of the "session" cookie on the aoc website
+        #[arg(short, long, env = "AOC_SESSION_ID")]
+        session_id: String,
+
+        /// First record the answers accepted on the aoc website for all days that are missing
+        /// some, e.g. because they were submitted in the browser
+        #[arg(short, long)]
+        fetch_answers: bool,
+    },
+
+    /// Print the puzzle description of an advent day as Markdown
+    Read {
+        /// The advent day whose puzzle to print
+        advent_day: u8,
+
+        /// The value of the "session" cookie on the aoc website
+        #[arg(short, long, env = "AOC_SESSION_ID")]
+        session_id: String,
+
+        /// Download the description again, e.g. to get part two after solving part one
+        #[arg(short, long)]
+        refresh: bool,
+    },
+
+    /// Check the solver of an advent day against the examples in the puzzle description
+    Examples {
+        /// The advent day whose examples to check
+        advent_day: u8,
+
+        /// The value of the "session" cookie on the aoc website
+        #[arg(short, long, env = "AOC_SESSION_ID")]
+        session_id: String,
+
+        /// Download the description again, e.g. to get the examples of part two
+        #[arg(short, long)]
+        refresh: bool,
+    },
+
+    /// Generate the module of a new advent day and register its solver
+    NewDay {
+        /// The advent day to generate the module for
+        advent_day: u8,
+
+        /// The value of the "session" cookie on the aoc website, to fill in the title and the
+        /// example test from the puzzle description
+        #[arg(short, long, env = "AOC_SESSION_ID")]
+        session_id: Option<String>,
+
+        /// The title of the puzzle, if not taken from the puzzle description
+        #[arg(short, long)]
+        title: Option<String>,
+    },
+
+    /// Inspect and manage the cached puzzle inputs
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommand,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum CacheCommand {
+    /// Print the location of the cache directory
+    Path,
+
+    /// List the cached inputs of all sessions
+    List,
+
+    /// Print the cached input of an advent day
+    Show {
+        /// The advent day whose input to print
+        advent_day: u8,
+
+        /// The value of the "session" cookie on the aoc website
+        #[arg(short, long, env = "AOC_SESSION_ID")]
+        session_id: String,
+    },
+
+    /// Delete cached inputs, keeping accepted answers
+    Clear {
+        /// Only delete the input of this advent day
+        #[arg(short, long)]
+        day: Option<u8>,
+
+        /// Only delete the inputs of this session instead of those of all sessions
+        #[arg(short, long, env = "AOC_SESSION_ID")]
+        session_id: Option<String>,
+    },
+
+    /// Copy the caches of all sessions into a directory
+    Export {
+        /// The directory to export to
+        dir: PathBuf,
+    },
+
+    /// Copy session caches from a directory created by `cache export` into the cache
+    Import {
+        /// The directory to import from
+        dir: PathBuf,
+    },
+}
+
+#[derive(clap::Args, Debug)]
+struct SolveArgs {
     /// The advent day to solve
     advent_day: u8,
 
@@ -25,9 +251,43 @@ struct Args {
     #[arg(value_enum)]
     part: Part,
 
+    #[command(flatten)]
+    input: InputArgs,
+
+    /// Run the solver RUNS times and report parse and estimated solve timings
+    #[arg(
+        long,
+        value_name = "RUNS",
+        num_args = 0..=1,
+        default_missing_value = "10",
+        value_parser = clap::value_parser!(u32).range(1..)
+    )]
+    bench: Option<u32>,
+
+    /// How to print the solution
+    #[arg(long, value_enum, default_value_t, conflicts_with = "bench")]
+    format: OutputFormat,
+}
+
+/// Where to take the puzzle input from.
+#[derive(clap::Args, Debug)]
+struct InputArgs {
     /// The value of the "session" cookie on the aoc website
-    #[arg(short, long, env = "AOC_SESSION_ID")]
-    session_id: String,
+    #[arg(
+        short,
+        long,
+        env = "AOC_SESSION_ID",
+        required_unless_present_any = ["example", "input"]
+    )]
+    session_id: Option<String>,
+
+    /// Solve the example from the puzzle text instead of the actual puzzle input
+    #[arg(short, long, conflicts_with = "input")]
+    example: bool,
+
+    /// Read the puzzle input from this file ("-" for stdin) instead of the cache or the aoc website
+    #[arg(short, long, value_name = "PATH")]
+    input: Option<PathBuf>,
 }
 
 fn main() -> Result<()> {
@@ -35,64 +295,480 @@ fn main() -> Result<()> {
 
     let args = Args::parse();
 
-    let package_name = env!("CARGO_PKG_NAME");
-    let project_dir =
-        directories::ProjectDirs::from("", "AndreasKargSoftware", package_name).unwrap();
+    let year = args.year;
+    match args.command {
+        Command::Solve(solve_args) => solve(year, solve_args, &args.remote),
+        Command::Submit {
+            advent_day,
+            part,
+            session_id,
+        } => submit(year, advent_day, part, &session_id, &args.remote),
+        Command::Watch {
+            advent_day,
+            part,
+            input,
+        } => watch(year, advent_day, part, &input, &args.remote),
+        Command::List => list(year),
+        Command::RunAll { session_id, format } => run_all(year, &session_id, format, &args.remote),
+        Command::Verify {
+            session_id,
+            fetch_answers,
+        } => verify(year, &session_id, fetch_answers, &args.remote),
+        Command::Read {
+            advent_day,
+            session_id,
+            refresh,
+        } => {
+            let cache = open_cache(&session_id, year)?;
+            let client = args.remote.client(&session_id)?;
+            print!("{}", cache.load_puzzle(advent_day, &client, refresh)?);
+            Ok(())
+        }
+        Command::Examples {
+            advent_day,
+            session_id,
+            refresh,
+        } => check_examples(year, advent_day, &session_id, refresh, &args.remote),
+        Command::NewDay {
+            advent_day,
+            session_id,
+            title,
+        } => new_day(year, advent_day, session_id.as_deref(), title, &args.remote),
+        Command::Cache { command } => manage_cache(year, command),
+    }
+}
+
+fn new_day(
+    year: u16,
+    advent_day: u8,
+    session_id: Option<&str>,
+    title: Option<String>,
+    remote: &RemoteArgs,
+) -> Result<()> {
+    let puzzle = match session_id {
+        Some(session_id) => {
+            let cache = open_cache(session_id, year)?;
+            Some(cache.load_puzzle(advent_day, &remote.client(session_id)?, false)?)
+        }
+        None => None,
+    };
+    let title = title
+        .or_else(|| {
+            puzzle
+                .as_deref()
+                .and_then(scaffold::puzzle_title)
+                .map(str::to_owned)
+        })
+        .unwrap_or_else(|| "TODO".to_owned());
+    let examples = puzzle.as_deref().map(examples::extract).unwrap_or_default();
+
+    let days_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("days");
+    let module_path = scaffold::create_day(&days_dir, year, advent_day, &title, &examples)?;
+
+    println!("Created {} for \"{title}\".", module_path.display());
+
+    Ok(())
+}
+
+fn manage_cache(year: u16, command: CacheCommand) -> Result<()> {
+    match command {
+        CacheCommand::Path => println!("{}", cache::cache_dir().display()),
+        CacheCommand::List => {
+            for session in SessionCache::all()? {
+                println!("Session cache {}:", session.dir().display());
+                for input in session.inputs()? {
+                    println!(
+                        "  Day {:>2}  {:>8} bytes  fetched {}",
+                        input.advent_day,
+                        input.size,
+                        humantime::format_rfc3339_seconds(input.fetched_at)
+                    );
+                }
+            }
+        }
+        CacheCommand::Show {
+            advent_day,
+            session_id,
+        } => {
+            let input = open_cache(&session_id, year)?
+                .read_input(advent_day)?
+                .ok_or_else(|| eyre!("There is no cached input for day {advent_day}!"))?;
+            print!("{input}");
+        }
+        CacheCommand::Clear { day, session_id } => {
+            let sessions = match session_id {
+                Some(session_id) => vec![open_cache(&session_id, year)?],
+                None => SessionCache::all()?
+                    .into_iter()
+                    .filter(|session| session.year() == year)
+                    .collect(),
+            };
+
+            let mut removed_count = 0;
+            for session in sessions {
+                let days: Vec<_> = match day {
+                    Some(day) => vec![day],
+                    None => session
+                        .inputs()?
+                        .into_iter()
+                        .map(|input| input.advent_day)
+                        .collect(),
+                };
+
+                for day in days {
+                    if session.remove_input(day)? {
+                        removed_count += 1;
+                    }
+                }
+            }
+
+            println!("Deleted {removed_count} cached inputs.");
+        }
+        CacheCommand::Export { dir } => {
+            let report = cache::copy_sessions(&cache::cache_dir(), &dir)?;
+            warn_skipped(&report);
+            println!(
+                "Exported {} files to {}.",
+                report.copied_count,
+                dir.display()
+            );
+        }
+        CacheCommand::Import { dir } => {
+            let report = cache::copy_sessions(&dir, &cache::cache_dir())?;
+            warn_skipped(&report);
+            println!(
+                "Imported {} files from {}.",
+                report.copied_count,
+                dir.display()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn warn_skipped(report: &cache::CopyReport) {
+    for path in &report.skipped {
+        eprintln!(
+            "Warning: skipped {}, which is not part of a session cache.",
+            path.display()
+        );
+    }
+}
+
+/// Opens the cache of the session, telling if it had to be moved from its legacy location.
+fn open_cache(session_id: &str, year: u16) -> Result<SessionCache> {
+    let cache = SessionCache::new(session_id, year)?;
+    if let Some(migration) = cache.migration() {
+        eprintln!(
+            "Migrated cache from {} to {}.",
+            migration.from.display(),
+            migration.to.display()
+        );
+    }
+
+    Ok(cache)
+}
+
+fn list(year: u16) -> Result<()> {
+    for solver in find_year(year)?.solvers {
+        println!("Day {:>2}: {}", solver.day(), solver.title());
+    }
+
+    Ok(())
+}
+
+fn find_year(year: u16) -> Result<&'static days::Year> {
+    days::find_year(year).ok_or_else(|| {
+        let available_years = days::YEARS.iter().map(|year| year.year).join(", ");
+        eyre!("There are no solvers for {year} yet! Available years: {available_years}")
+    })
+}
+
+fn find_solver(year: u16, advent_day: u8) -> Result<&'static dyn Solver> {
+    let solvers = find_year(year)?;
+
+    solvers.find(advent_day).ok_or_else(|| {
+        let available_days = solvers.solvers.iter().map(|solver| solver.day()).join(", ");
+        eyre!("Day {advent_day} of {year} has no solver yet! Available days: {available_days}")
+    })
+}
+
+fn solve(year: u16, args: SolveArgs, remote: &RemoteArgs) -> Result<()> {
+    let advent_day = args.advent_day;
+    let solver = find_solver(year, advent_day)?;
+
+    let input = load_solver_input(year, solver, &args.input, remote);
+    if let Ok(input) = &input {
+        report_input_source(advent_day, input.source);
+    }
+
+    if let Some(runs) = args.bench {
+        let report = bench::run(solver, args.part, &input?.text, runs as usize)?;
+        println!("{report}");
+
+        return Ok(());
+    }
+
+    match args.format {
+        OutputFormat::Text => {
+            let timing = bench::time(solver, args.part, &input?.text)?;
+            println!("The puzzle solution is:\n{}", timing.solution);
+        }
+        OutputFormat::Json => {
+            // Failures are reported in the JSON as well, so that scripts can tell them apart.
+            let report = match input {
+                Ok(input) => match catch_panic(|| bench::time(solver, args.part, &input.text))
+                    .and_then(|timing| timing)
+                {
+                    Ok(timing) => {
+                        RunReport::solved(year, advent_day, args.part, input.source, &timing)
+                    }
+                    Err(e) => RunReport::failed(
+                        year,
+                        advent_day,
+                        args.part,
+                        Some(input.source),
+                        format!("{e:#}"),
+                    ),
+                },
+                Err(e) => RunReport::failed(year, advent_day, args.part, None, format!("{e:#}")),
+            };
+            println!("{}", report.to_json());
+
+            if let Some(error) = report.error {
+                bail!(error);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Loads the puzzle input selected by the command line arguments.
+fn load_solver_input(
+    year: u16,
+    solver: &dyn Solver,
+    args: &InputArgs,
+    remote: &RemoteArgs,
+) -> Result<LoadedInput> {
+    match (&args.input, &args.session_id) {
+        (Some(path), _) => cache::load_solver_input(solver, &InputSelection::File(path)),
+        (None, Some(session_id)) if !args.example => {
+            let cache = open_cache(session_id, year)?;
+            let client = remote.client(session_id)?;
+            let selection = InputSelection::Session {
+                cache: &cache,
+                client: &client,
+            };
+
+            cache::load_solver_input(solver, &selection)
+        }
+        _ => cache::load_solver_input(solver, &InputSelection::Example),
+    }
+}
+
+/// Tells whether the input of the day came from the cache or was just downloaded.
+fn report_input_source(advent_day: u8, source: InputSource) {
+    match source {
+        InputSource::Cache => eprintln!("Using the cached input of day {advent_day}."),
+        InputSource::Download => {
+            eprintln!("No cached input found, downloaded a fresh copy of day {advent_day}.")
+        }
+        InputSource::File | InputSource::Stdin | InputSource::Example => {}
+    }
+}
+
+fn submit(
+    year: u16,
+    advent_day: u8,
+    part: Part,
+    session_id: &str,
+    remote: &RemoteArgs,
+) -> Result<()> {
+    let solver = find_solver(year, advent_day)?;
+    let cache = open_cache(session_id, year)?;
+    let client = remote.client(session_id)?;
+    let input = cache.load_input(advent_day, &client)?;
+    report_input_source(advent_day, input.source);
+
+    let solution = part.solve(solver, &input.text)?;
 
-    let mut hasher = DefaultHasher::new();
-    args.session_id.hash(&mut hasher);
-    let session_id_hash = format!("{:x}", hasher.finish());
+    let rejections = cache.read_rejections(advent_day, part)?;
+    if let Some(reason) = submission::known_wrong(&solution, &rejections) {
+        bail!("Not submitting solution {solution}, it is wrong for sure: {reason}!");
+    }
 
-    let cache_dir = project_dir.cache_dir();
-    let session_cache_dir = cache_dir.join(&session_id_hash);
-    let cache_file_name = format!("day_{}.txt", args.advent_day);
-    let cache_file_path = session_cache_dir.join(cache_file_name);
-    let stringified_cache_file_path = cache_file_path.to_str().unwrap().to_owned();
+    println!("Submitting solution {solution}...");
 
-    let input = if cache_file_path.exists() {
-        println!(
-            "Using cached inputs from {}...",
-            stringified_cache_file_path
+    let outcome = submission::submit(&client, year, advent_day, part, &solution)?;
+
+    println!("{outcome}");
+
+    if outcome == submission::Outcome::Correct {
+        cache.write_answer(advent_day, part, &solution)?;
+    } else if let Some(rejection) = submission::Rejection::new(solution, &outcome) {
+        cache.add_rejection(advent_day, part, &rejection)?;
+    }
+
+    Ok(())
+}
+
+fn run_all(year: u16, session_id: &str, format: OutputFormat, remote: &RemoteArgs) -> Result<()> {
+    let cache = open_cache(session_id, year)?;
+    let reports = report::run_all(find_year(year)?, &cache, &remote.client(session_id)?);
+
+    match format {
+        OutputFormat::Text => print!("\n{}", report::table(&reports)),
+        OutputFormat::Json => {
+            for report in &reports {
+                println!("{}", report.to_json());
+            }
+        }
+    }
+
+    let failure_count = reports
+        .iter()
+        .filter(|report| report.error.is_some())
+        .count();
+    if failure_count > 0 {
+        bail!("{failure_count} of {} tasks failed!", reports.len());
+    }
+
+    Ok(())
+}
+
+fn verify(year: u16, session_id: &str, fetch_answers: bool, remote: &RemoteArgs) -> Result<()> {
+    let cache = open_cache(session_id, year)?;
+    let client = remote.client(session_id)?;
+    let verify::Verification {
+        verified_count,
+        failures,
+        fetch_failures,
+    } = verify::verify(
+        find_year(year)?,
+        &cache,
+        &client,
+        fetch_answers,
+        |advent_day| eprintln!("Fetching the accepted answers of day {advent_day}..."),
+    )?;
+
+    println!();
+    for failure in fetch_failures.iter().chain(&failures) {
+        println!("{failure}");
+    }
+
+    if !failures.is_empty() {
+        bail!(
+            "{} of {verified_count} accepted answers could not be reproduced!",
+            failures.len()
         );
-        let input = read(cache_file_path).wrap_err_with(|| {
-            format!("Unable to read cache file at {stringified_cache_file_path}!")
-        })?;
-        String::from_utf8(input)
-            .wrap_err_with(|| format!("Invalid UTF-8 in {stringified_cache_file_path}!"))?
-    } else {
-        println!("No cached input found. Downloading fresh copy...");
-        let response_body = input_fetcher::fetch(args.advent_day, &args.session_id);
-
-        let stringified_cache_dir = cache_dir.to_str().unwrap().to_owned();
-        create_dir_all(session_cache_dir)
-            .wrap_err_with(|| format!("Unable to create dir {stringified_cache_dir}!"))?;
-        write(cache_file_path, &response_body)
-            .wrap_err_with(|| format!("Unable to write to {stringified_cache_file_path}!"))?;
-
-        response_body
-    };
+    }
 
-    let solver = match (args.advent_day, args.part) {
-        (1, Part::One) => days::day_1::solve_part_1,
-        (1, Part::Two) => days::day_1::solve_part_2,
-        (2, Part::One) => days::day_2::solve_part_1,
-        (2, Part::Two) => days::day_2::solve_part_2,
-        (3, Part::One) => days::day_3::solve_part_1,
-        (3, Part::Two) => days::day_3::solve_part_2,
-        (4, Part::One) => days::day_4::solve_part_1,
-        (4, Part::Two) => days::day_4::solve_part_2,
-        (5, Part::One) => days::day_5::solve_part_1,
-        (5, Part::Two) => days::day_5::solve_part_2,
-        (6, Part::One) => days::day_6::solve_part_1,
-        (6, Part::Two) => days::day_6::solve_part_2,
-        (7, Part::One) => days::day_7::solve_part_1,
-        (7, Part::Two) => days::day_7::solve_part_2,
-        _ => panic!("Unknown combo of advent day and puzzle part."),
-    };
+    if !fetch_failures.is_empty() {
+        bail!(
+            "The accepted answers of {} days could not be fetched!",
+            fetch_failures.len()
+        );
+    }
 
-    let solution = solver(&input);
+    if verified_count == 0 {
+        bail!(
+            "There are no accepted answers to verify! Pass --fetch-answers to record the answers \
+            accepted on the aoc website."
+        );
+    }
 
-    println!("The puzzle solution is:\n{solution}");
+    println!("All {verified_count} accepted answers reproduced.");
 
     Ok(())
 }
+
+fn check_examples(
+    year: u16,
+    advent_day: u8,
+    session_id: &str,
+    refresh: bool,
+    remote: &RemoteArgs,
+) -> Result<()> {
+    let solver = find_solver(year, advent_day)?;
+    let cache = open_cache(session_id, year)?;
+    let puzzle = cache.load_puzzle(advent_day, &remote.client(session_id)?, refresh)?;
+
+    let checked = examples::check_all(solver, &puzzle)?;
+
+    let mut failure_count = 0;
+    for (index, examples::CheckedExample { example, result }) in checked.iter().enumerate() {
+        let number = index + 1;
+        let part = example.part;
+        match result {
+            Ok(actual) => println!("PASS  Example {number} part {part:?}: {actual}"),
+            Err(e) => {
+                failure_count += 1;
+                println!("FAIL  Example {number} part {part:?}: {e:#}");
+            }
+        }
+    }
+
+    if failure_count > 0 {
+        bail!("{failure_count} of {} examples failed!", checked.len());
+    }
+
+    Ok(())
+}
+
+/// Runs the tests and then the solver of the day, again and again whenever its source file or
+/// input file changes.
+fn watch(
+    year: u16,
+    advent_day: u8,
+    part: Part,
+    input: &InputArgs,
+    remote: &RemoteArgs,
+) -> Result<()> {
+    find_solver(year, advent_day)?;
+
+    let watched_input = match &input.input {
+        Some(path) => WatchedInput::File(path.clone()),
+        None if input.example => WatchedInput::Example,
+        None => WatchedInput::Session,
+    };
+    let mut watch = Watch::new(
+        year,
+        advent_day,
+        part,
+        watched_input,
+        input.session_id.clone(),
+        remote.to_args(),
+    )?;
+    let mut previous_answer: Option<String> = None;
+
+    loop {
+        eprintln!("Running the tests of day {advent_day}...");
+        if watch.run_tests()? {
+            eprintln!("Running the solver of day {advent_day} part {part:?}...");
+            match watch.run_solver()? {
+                Some(answer) => {
+                    match &previous_answer {
+                        Some(previous_answer) => {
+                            println!("Answer:\n{}", watch::diff_answers(previous_answer, &answer))
+                        }
+                        None => println!("Answer:\n{answer}"),
+                    }
+                    previous_answer = Some(answer);
+                }
+                None => eprintln!("The solver failed!"),
+            }
+        } else {
+            eprintln!("The tests failed, so the solver was not run.");
+        }
+
+        eprintln!(
+            "Waiting for changes to {}...",
+            watch.paths().iter().map(|path| path.display()).join(", ")
+        );
+        watch.wait_for_changes();
+    }
+}
@@ -0,0 +1,278 @@
+//! Turns the puzzle descriptions on the aoc website into Markdown.
+
+use crate::answer::Answer;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn to_markdown_renders_all_puzzle_descriptions_of_the_page() {
+        // Given
+        let page = indoc! {r#"
+            <html><body><main>
+            <article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>The jungle must be too overgrown and <em>difficult</em> to navigate.</p>
+            <p>For example, suppose the Elves finish writing their items' <a href="/2022/about">Calories</a> into a list:</p>
+            <pre><code>1000
+            2000
+
+            3000
+            </code></pre>
+            <ul>
+            <li>The first Elf is carrying food with <code>1000</code> and <code>2000</code> Calories.</li>
+            <li>The second Elf is carrying <span title="Snacks!">one</span> food item.</li>
+            </ul>
+            <p>In the example above, this is <em><code>24000</code></em> or <code><em>24000</em></code> &amp; that's it.</p>
+            </article>
+            <p>Your puzzle answer was <code>70509</code>.</p>
+            <article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Find the top <em>three</em> Elves.</p>
+            </article>
+            </main></body></html>
+        "#};
+
+        let expected_markdown = indoc! {"
+            ## --- Day 1: Calorie Counting ---
+
+            The jungle must be too overgrown and *difficult* to navigate.
+
+            For example, suppose the Elves finish writing their items' [Calories](/2022/about) into a list:
+
+            ```
+            1000
+            2000
+
+            3000
+            ```
+
+            - The first Elf is carrying food with `1000` and `2000` Calories.
+            - The second Elf is carrying one food item.
+
+            In the example above, this is *`24000`* or *`24000`* & that's it.
+
+            ## --- Part Two ---
+
+            Find the top *three* Elves.
+        "};
+
+        // When
+        let markdown = to_markdown(page);
+
+        // Then
+        assert_eq!(markdown, expected_markdown);
+    }
+
+    #[test]
+    fn accepted_answers_returns_the_answers_below_the_descriptions_in_order_of_parts() {
+        // Given
+        let page = indoc! {r#"
+            <article class="day-desc"><h2>--- Day 10: Cathode-Ray Tube ---</h2></article>
+            <p>Your puzzle answer was <code>13140</code>.</p>
+            <article class="day-desc"><h2 id="part2">--- Part Two ---</h2></article>
+            <p>Your puzzle answer was <code>RZEKEFHA</code>.</p>
+        "#};
+
+        // When
+        let answers = accepted_answers(page);
+
+        // Then
+        assert_eq!(answers, vec![Answer::from(13140), Answer::from("RZEKEFHA")]);
+    }
+
+    #[test]
+    fn decode_entities_replaces_html_entities() {
+        // Given
+        let text = "&lt;x&gt; &amp;&amp; &quot;y&quot; &#39;z&#39; &unknown;";
+
+        // When
+        let decoded = decode_entities(text);
+
+        // Then
+        assert_eq!(decoded, r#"<x> && "y" 'z' &unknown;"#);
+    }
+}
+
+/// The heading the Markdown of a puzzle contains once part two is unlocked.
+pub const PART_TWO_HEADING: &str = "## --- Part Two ---";
+
+/// Renders every `<article class="day-desc">` of a puzzle page as Markdown.
+///
+/// The page contains one article per unlocked part, so the Markdown contains the description of
+/// part two only once part one is solved.
+pub fn to_markdown(page: &str) -> String {
+    let articles: Vec<_> = day_descriptions(page)
+        .into_iter()
+        .map(|article| article_to_markdown(article).trim().to_owned())
+        .collect();
+
+    let mut markdown = articles.join("\n\n");
+    markdown.push('\n');
+    markdown
+}
+
+/// The answers the website accepted for the puzzle, which the page shows below the description
+/// of each solved part.
+pub fn accepted_answers(page: &str) -> Vec<Answer> {
+    let prefix = "Your puzzle answer was <code>";
+
+    page.split(prefix)
+        .skip(1)
+        .filter_map(|rest| {
+            Some(Answer::parse(&decode_entities(
+                rest.split_once("</code>")?.0,
+            )))
+        })
+        .collect()
+}
+
+/// The inner HTML of all `<article class="day-desc">` elements of the page.
+fn day_descriptions(page: &str) -> Vec<&str> {
+    let opening_tag = r#"<article class="day-desc">"#;
+    let closing_tag = "</article>";
+
+    let mut articles = Vec::new();
+    let mut rest = page;
+    while let Some(start) = rest.find(opening_tag) {
+        let article_start = start + opening_tag.len();
+        let Some(length) = rest[article_start..].find(closing_tag) else {
+            break;
+        };
+
+        articles.push(&rest[article_start..article_start + length]);
+        rest = &rest[article_start + length + closing_tag.len()..];
+    }
+
+    articles
+}
+
+enum Token<'a> {
+    Text(&'a str),
+    Open {
+        name: &'a str,
+        href: Option<&'a str>,
+    },
+    Close(&'a str),
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(tag_start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+        if tag_start > 0 {
+            tokens.push(Token::Text(&rest[..tag_start]));
+        }
+
+        let Some(tag_length) = rest[tag_start..].find('>') else {
+            tokens.push(Token::Text(&rest[tag_start..]));
+            break;
+        };
+        let tag = &rest[tag_start + 1..tag_start + tag_length];
+        rest = &rest[tag_start + tag_length + 1..];
+
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::Close(name.trim()));
+        } else {
+            let name = tag.split_whitespace().next().unwrap_or_default();
+            let href = tag
+                .split_once("href=\"")
+                .and_then(|(_, href)| href.split_once('"'))
+                .map(|(href, _)| href);
+            tokens.push(Token::Open { name, href });
+        }
+    }
+
+    tokens
+}
+
+fn article_to_markdown(article: &str) -> String {
+    let mut markdown = String::new();
+    let mut in_pre = false;
+    let mut inline_code_start = None;
+    let mut emphasised_code = false;
+    let mut hrefs = Vec::new();
+
+    for token in tokenize(article) {
+        match token {
+            Token::Text(text) if in_pre => markdown.push_str(&decode_entities(text)),
+            Token::Text(text) => {
+                // Line breaks between tags are formatting of the HTML source, not content.
+                let text = decode_entities(text);
+                if text.trim().is_empty() && text.contains('\n') {
+                    continue;
+                }
+                markdown.push_str(&text);
+            }
+            Token::Open { name, href } => match name {
+                "h2" => markdown.push_str("## "),
+                "pre" => {
+                    in_pre = true;
+                    markdown.push_str("```\n");
+                }
+                "code" if !in_pre => {
+                    inline_code_start = Some(markdown.len());
+                    markdown.push('`');
+                }
+                "em" if in_pre => {}
+                // `<code><em>x</em></code>` is rendered like `<em><code>x</code></em>`,
+                // because emphasis inside of code spans does not exist in Markdown.
+                "em" => match inline_code_start {
+                    Some(code_start) => {
+                        markdown.insert(code_start, '*');
+                        emphasised_code = true;
+                    }
+                    None => markdown.push('*'),
+                },
+                "li" => markdown.push_str("- "),
+                "a" => {
+                    hrefs.push(href.unwrap_or_default());
+                    markdown.push('[');
+                }
+                _ => {}
+            },
+            Token::Close(name) => match name {
+                "h2" | "p" => markdown.push_str("\n\n"),
+                // Every list item already ends with a line break.
+                "ul" => markdown.push('\n'),
+                "pre" => {
+                    in_pre = false;
+                    if !markdown.ends_with('\n') {
+                        markdown.push('\n');
+                    }
+                    markdown.push_str("```\n\n");
+                }
+                "code" if !in_pre => {
+                    markdown.push('`');
+                    inline_code_start = None;
+                    if emphasised_code {
+                        markdown.push('*');
+                        emphasised_code = false;
+                    }
+                }
+                "em" if in_pre || emphasised_code => {}
+                "em" => markdown.push('*'),
+                "li" => markdown.push('\n'),
+                "a" => {
+                    let href = hrefs.pop().unwrap_or_default();
+                    markdown.push_str(&format!("]({href})"));
+                }
+                _ => {}
+            },
+        }
+    }
+
+    markdown
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
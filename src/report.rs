@@ -0,0 +1,250 @@
+//! Machine-readable results of solver runs, as printed with `--format json`.
+
+use clap::ValueEnum;
+use serde::{Serialize, Serializer};
+use std::time::Duration;
+
+use crate::answer::Answer;
+use crate::bench::{self, Timing};
+use crate::cache::SessionCache;
+use crate::days::Year;
+use crate::input_fetcher::Client;
+use crate::{catch_panic, Part};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn run_report_is_serialized_as_a_single_line_of_json() {
+        // Given
+        let timing = Timing {
+            solution: Answer::from(24000u32),
+            parse: Duration::from_micros(1500),
+            solve: Duration::from_micros(250),
+        };
+
+        // When
+        let report = RunReport::solved(2022, 1, Part::One, InputSource::Cache, &timing);
+
+        // Then
+        assert_eq!(
+            report.to_json(),
+            r#"{"year":2022,"day":1,"part":1,"answer":24000,"error":null,"parse_seconds":0.0015,"solve_seconds":0.00025,"input_source":"cache","cache_hit":true}"#
+        );
+    }
+
+    #[test]
+    fn table_has_a_row_per_part_with_the_solution_or_the_error() {
+        // Given
+        let timing = Timing {
+            solution: Answer::from(24000u32),
+            parse: Duration::from_micros(1500),
+            solve: Duration::from_micros(250),
+        };
+        let reports = [
+            RunReport::solved(2022, 1, Part::One, InputSource::Cache, &timing),
+            RunReport::failed(2022, 1, Part::Two, None, "Oops".to_owned()),
+        ];
+
+        // When
+        let table = table(&reports);
+
+        // Then
+        assert_eq!(
+            table,
+            indoc! {"
+                Day  Part  Solution                    Time
+                  1  One   24000                     1.75ms
+                  1  Two   FAILED: Oops
+            "}
+        );
+    }
+
+    #[test]
+    fn cache_hit_is_only_known_for_inputs_of_the_session() {
+        // Given
+        let sources = [
+            InputSource::Cache,
+            InputSource::Download,
+            InputSource::File,
+            InputSource::Stdin,
+            InputSource::Example,
+        ];
+
+        // When
+        let cache_hits: Vec<_> = sources.into_iter().map(InputSource::cache_hit).collect();
+
+        // Then
+        assert_eq!(cache_hits, vec![Some(true), Some(false), None, None, None]);
+    }
+}
+
+#[derive(ValueEnum, Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum OutputFormat {
+    /// Human-readable text
+    #[default]
+    Text,
+    /// A JSON object per solved part, one per line
+    Json,
+}
+
+/// Where the puzzle input of a run came from.
+#[derive(Serialize, Copy, Clone, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum InputSource {
+    Cache,
+    Download,
+    File,
+    Stdin,
+    Example,
+}
+
+impl InputSource {
+    /// Whether the input of the session was found in the cache, if it is the input of the session.
+    pub fn cache_hit(self) -> Option<bool> {
+        match self {
+            InputSource::Cache => Some(true),
+            InputSource::Download => Some(false),
+            InputSource::File | InputSource::Stdin | InputSource::Example => None,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct RunReport {
+    pub year: u16,
+    pub day: u8,
+    #[serde(serialize_with = "serialize_level")]
+    pub part: Part,
+    pub answer: Option<Answer>,
+    pub error: Option<String>,
+    pub parse_seconds: Option<f64>,
+    pub solve_seconds: Option<f64>,
+    pub input_source: Option<InputSource>,
+    pub cache_hit: Option<bool>,
+}
+
+impl RunReport {
+    pub fn solved(
+        year: u16,
+        advent_day: u8,
+        part: Part,
+        input_source: InputSource,
+        timing: &Timing,
+    ) -> Self {
+        Self {
+            year,
+            day: advent_day,
+            part,
+            answer: Some(timing.solution.clone()),
+            error: None,
+            parse_seconds: Some(timing.parse.as_secs_f64()),
+            solve_seconds: Some(timing.solve.as_secs_f64()),
+            input_source: Some(input_source),
+            cache_hit: input_source.cache_hit(),
+        }
+    }
+
+    pub fn failed(
+        year: u16,
+        advent_day: u8,
+        part: Part,
+        input_source: Option<InputSource>,
+        error: String,
+    ) -> Self {
+        Self {
+            year,
+            day: advent_day,
+            part,
+            answer: None,
+            error: Some(error),
+            parse_seconds: None,
+            solve_seconds: None,
+            input_source,
+            cache_hit: input_source.and_then(InputSource::cache_hit),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Run reports are always serializable!")
+    }
+}
+
+/// Serializes a part as the number the aoc website uses to identify it.
+fn serialize_level<S: Serializer>(part: &Part, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u8(part.level())
+}
+
+/// Solves both parts of every day of `year` with the input of the session, downloading missing
+/// inputs with `client`.
+///
+/// Days whose input cannot be loaded and solvers that fail or panic end up as failed reports.
+pub fn run_all(year: &Year, cache: &SessionCache, client: &Client) -> Vec<RunReport> {
+    let mut reports = Vec::new();
+
+    for solver in year.solvers {
+        let advent_day = solver.day();
+        let input = cache.load_input(advent_day, client);
+
+        for part in Part::ALL {
+            let report = match &input {
+                Ok(input) => match catch_panic(|| bench::time(*solver, part, &input.text))
+                    .and_then(|timing| timing)
+                {
+                    Ok(timing) => {
+                        RunReport::solved(year.year, advent_day, part, input.source, &timing)
+                    }
+                    Err(e) => RunReport::failed(
+                        year.year,
+                        advent_day,
+                        part,
+                        Some(input.source),
+                        format!("{e:#}"),
+                    ),
+                },
+                Err(e) => RunReport::failed(
+                    year.year,
+                    advent_day,
+                    part,
+                    None,
+                    format!("Unable to load input: {e}"),
+                ),
+            };
+
+            reports.push(report);
+        }
+    }
+
+    reports
+}
+
+/// The reports as a table with a row per part, showing the solution and the time it took.
+pub fn table(reports: &[RunReport]) -> String {
+    let mut table = format!(
+        "{:>3}  {:<4}  {:<20}  {:>10}\n",
+        "Day", "Part", "Solution", "Time"
+    );
+    for report in reports {
+        let day = report.day;
+        let part = format!("{:?}", report.part);
+        let row = match (&report.answer, &report.error) {
+            (Some(solution), _) => {
+                let seconds = report.parse_seconds.unwrap_or_default()
+                    + report.solve_seconds.unwrap_or_default();
+                let elapsed = format!("{:.2?}", Duration::from_secs_f64(seconds));
+                format!("{day:>3}  {part:<4}  {solution:<20}  {elapsed:>10}")
+            }
+            (None, error) => format!(
+                "{day:>3}  {part:<4}  FAILED: {}",
+                error.as_deref().unwrap_or_default()
+            ),
+        };
+        table.push_str(&row);
+        table.push('\n');
+    }
+
+    table
+}
@@ -0,0 +1,607 @@
+//! Generates the skeleton of a new day module and registers it in the solver registries.
+
+use color_eyre::{
+    eyre::{bail, eyre, Context},
+    Result,
+};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::answer::Answer;
+use crate::examples::Example;
+use crate::Part;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    const YEAR_MOD_RS: &str = indoc! {"
+        pub mod day_1;
+        pub mod day_9;
+
+        use super::Solver;
+
+        pub static SOLVERS: &[&dyn Solver] = &[&day_1::Day1, &day_9::Day9];
+    "};
+
+    #[test]
+    fn register_day_inserts_module_and_solver_in_order_of_days() {
+        // Given
+        let advent_day = 8;
+
+        // When
+        let mod_rs = register_day(YEAR_MOD_RS, advent_day).unwrap();
+
+        // Then
+        assert_eq!(
+            mod_rs,
+            indoc! {"
+                pub mod day_1;
+                pub mod day_8;
+                pub mod day_9;
+
+                use super::Solver;
+
+                pub static SOLVERS: &[&dyn Solver] = &[&day_1::Day1, &day_8::Day8, &day_9::Day9];
+            "}
+        );
+    }
+
+    #[test]
+    fn register_day_sorts_module_declarations_by_name_like_rustfmt() {
+        // Given
+        let mod_rs = indoc! {"
+            pub mod day_1;
+            pub mod day_10;
+            pub mod day_9;
+
+            use super::Solver;
+
+            pub static SOLVERS: &[&dyn Solver] = &[&day_1::Day1, &day_9::Day9, &day_10::Day10];
+        "};
+
+        // When
+        let mod_rs = register_day(mod_rs, 13).unwrap();
+
+        // Then
+        assert_eq!(
+            mod_rs,
+            indoc! {"
+                pub mod day_1;
+                pub mod day_10;
+                pub mod day_13;
+                pub mod day_9;
+
+                use super::Solver;
+
+                pub static SOLVERS: &[&dyn Solver] = &[&day_1::Day1, &day_9::Day9, &day_10::Day10, &day_13::Day13];
+            "}
+        );
+    }
+
+    #[test]
+    fn register_day_fails_for_registered_days() {
+        // Given
+        let advent_day = 9;
+
+        // When
+        let result = register_day(YEAR_MOD_RS, advent_day);
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn register_year_breaks_the_registry_into_lines_once_it_gets_too_long() {
+        // Given
+        let mod_rs = indoc! {"
+            pub mod year_2022;
+
+            pub static YEARS: &[Year] = &[Year::new(2022, year_2022::SOLVERS)];
+        "};
+
+        // When
+        let mod_rs = register_year(mod_rs, 2015).unwrap();
+
+        // Then
+        assert_eq!(
+            mod_rs,
+            indoc! {"
+                pub mod year_2015;
+                pub mod year_2022;
+
+                pub static YEARS: &[Year] = &[
+                    Year::new(2015, year_2015::SOLVERS),
+                    Year::new(2022, year_2022::SOLVERS),
+                ];
+            "}
+        );
+    }
+
+    #[test]
+    fn year_module_registers_its_first_day() {
+        // Given
+        let year = 2015;
+
+        // When
+        let mod_rs = year_module(year, 3);
+
+        // Then
+        assert_eq!(
+            mod_rs,
+            indoc! {"
+                pub mod day_3;
+
+                use super::Solver;
+
+                /// All implemented days of 2015, in ascending order.
+                pub static SOLVERS: &[&dyn Solver] = &[&day_3::Day3];
+            "}
+        );
+    }
+
+    #[test]
+    fn day_module_prefills_the_example_test() {
+        // Given
+        let examples = vec![Example {
+            part: Part::One,
+            input: "30373\n25512\n".to_owned(),
+            expected: Answer::Number(21),
+        }];
+
+        // When
+        let module = day_module(8, "Treetop Tree House", &examples);
+
+        // Then
+        assert!(module.contains("pub struct Day8;"));
+        assert!(module.contains(r#""Treetop Tree House""#));
+        assert!(module.contains("indoc! {r#\"\n    30373\n    25512\n\"#};"));
+        assert!(module.contains("assert_eq!(solution, Answer::Number(21));"));
+        assert!(module.contains(r#"assert_eq!(solution, Answer::from("TODO"));"#));
+    }
+
+    #[test]
+    fn day_module_delimits_the_example_by_more_hashes_than_it_contains() {
+        // Given
+        let examples = vec![Example {
+            part: Part::One,
+            input: "[\"#\"]\n[\"##\"]\n".to_owned(),
+            expected: Answer::Number(2),
+        }];
+
+        // When
+        let module = day_module(13, "Distress Signal", &examples);
+
+        // Then
+        assert!(module.contains("indoc! {r###\"\n    [\"#\"]\n    [\"##\"]\n\"###};"));
+    }
+
+    #[test]
+    fn day_module_keeps_the_leading_whitespace_of_the_example_without_indoc() {
+        // Given
+        let examples = vec![Example {
+            part: Part::One,
+            input: "    [D]\n[N] [C]\n 1   2\n".to_owned(),
+            expected: Answer::from("CMZ"),
+        }];
+
+        // When
+        let module = day_module(5, "Supply Stacks", &examples);
+
+        // Then
+        assert!(module.contains("const EXAMPLE_INPUT: &str = r#\"    [D]\n[N] [C]\n 1   2\n\"#;"));
+        assert!(!module.contains("indoc"));
+    }
+
+    #[test]
+    fn day_module_escapes_the_title() {
+        // Given
+        let title = r#"Distress "Signal" \o/"#;
+
+        // When
+        let module = day_module(13, title, &[]);
+
+        // Then
+        assert!(module.contains(r#""Distress \"Signal\" \\o/""#));
+    }
+
+    #[test]
+    fn create_day_adds_the_module_of_the_first_day_of_a_new_year() {
+        // Given
+        let temp_dir = TempDir::new();
+        let days_dir = temp_dir.path();
+        fs::write(
+            days_dir.join("mod.rs"),
+            indoc! {"
+                pub mod year_2022;
+
+                pub static YEARS: &[Year] = &[Year::new(2022, year_2022::SOLVERS)];
+            "},
+        )
+        .unwrap();
+
+        // When
+        let module_path = create_day(days_dir, 2015, 3, "Perfectly Spherical Houses", &[]).unwrap();
+
+        // Then
+        assert_eq!(module_path, days_dir.join("year_2015").join("day_3.rs"));
+        assert!(fs::read_to_string(&module_path)
+            .unwrap()
+            .contains("pub struct Day3;"));
+        assert_eq!(
+            fs::read_to_string(days_dir.join("year_2015").join("mod.rs")).unwrap(),
+            year_module(2015, 3)
+        );
+        assert!(fs::read_to_string(days_dir.join("mod.rs"))
+            .unwrap()
+            .contains("pub mod year_2015;"));
+        assert!(create_day(days_dir, 2015, 3, "Again", &[]).is_err());
+    }
+
+    #[test]
+    fn puzzle_title_returns_the_title_of_day_headings() {
+        // Given
+        let puzzle = "## --- Day 8: Treetop Tree House ---\n\nThe expedition comes across...\n";
+
+        // When
+        let title = puzzle_title(puzzle);
+
+        // Then
+        assert_eq!(title, Some("Treetop Tree House"));
+    }
+}
+
+/// The source code of a new day module with a solver that is yet to be written.
+///
+/// The tests of both parts use the first example of the part from `examples`, if any, so that
+/// they fail until the solver is implemented.
+pub fn day_module(advent_day: u8, title: &str, examples: &[Example]) -> String {
+    let example = |part| examples.iter().find(|example| example.part == part);
+    let expected = |part| match example(part).map(|example| &example.expected) {
+        Some(Answer::Number(number)) => format!("Answer::Number({number})"),
+        Some(Answer::Text(text)) => format!("Answer::from({text:?})"),
+        None => r#"Answer::from("TODO")"#.to_owned(),
+    };
+
+    let example_input = example(Part::One)
+        .map(|example| example.input.as_str())
+        .unwrap_or("TODO: paste the example input\n");
+    let indented = !example_input
+        .lines()
+        .any(|line| line.starts_with(char::is_whitespace));
+    let (indoc_import, example_input) = if indented {
+        ("use indoc::indoc;\n", indoc_literal(example_input))
+    } else {
+        ("", raw_string_literal(example_input))
+    };
+
+    format!(
+        r####"use color_eyre::Result;
+{indoc_import}
+use super::Solver;
+use crate::answer::Answer;
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    #[test]
+    fn solve_1_returns_the_solution_of_the_example() {{
+        // Given
+        // EXAMPLE_INPUT as below
+
+        // When
+        let solution = solve_part_1(EXAMPLE_INPUT).unwrap();
+
+        // Then
+        assert_eq!(solution, {expected_part_one});
+    }}
+
+    #[test]
+    fn solve_2_returns_the_solution_of_the_example() {{
+        // Given
+        // EXAMPLE_INPUT as below
+
+        // When
+        let solution = solve_part_2(EXAMPLE_INPUT).unwrap();
+
+        // Then
+        assert_eq!(solution, {expected_part_two});
+    }}
+}}
+
+const EXAMPLE_INPUT: &str = {example_input};
+
+pub struct Day{advent_day};
+
+impl Solver for Day{advent_day} {{
+    fn day(&self) -> u8 {{
+        {advent_day}
+    }}
+
+    fn title(&self) -> &'static str {{
+        {title:?}
+    }}
+
+    fn part_one(&self, input: &str) -> Result<Answer> {{
+        solve_part_1(input)
+    }}
+
+    fn part_two(&self, input: &str) -> Result<Answer> {{
+        solve_part_2(input)
+    }}
+
+    fn example_input(&self) -> Option<&'static str> {{
+        Some(EXAMPLE_INPUT)
+    }}
+}}
+
+pub fn solve_part_1(_input_data: &str) -> Result<Answer> {{
+    todo!("Solve part one of day {advent_day}")
+}}
+
+pub fn solve_part_2(_input_data: &str) -> Result<Answer> {{
+    todo!("Solve part two of day {advent_day}")
+}}
+"####,
+        expected_part_one = expected(Part::One),
+        expected_part_two = expected(Part::Two),
+    )
+}
+
+/// `text` as an indented raw string literal for `indoc!`, which strips the indentation again.
+fn indoc_literal(text: &str) -> String {
+    let hashes = raw_string_hashes(text);
+    let lines: String = text
+        .lines()
+        .map(|line| match line {
+            "" => "\n".to_owned(),
+            line => format!("    {line}\n"),
+        })
+        .collect();
+
+    format!("indoc! {{r{hashes}\"\n{lines}\"{hashes}}}")
+}
+
+/// `text` as a raw string literal, for text whose indentation must be kept as is.
+fn raw_string_literal(text: &str) -> String {
+    let hashes = raw_string_hashes(text);
+
+    format!("r{hashes}\"{text}\"{hashes}")
+}
+
+/// The delimiter of a raw string literal of `text`: one `#` more than `text` has after any `"`.
+fn raw_string_hashes(text: &str) -> String {
+    let longest_run = text
+        .split('"')
+        .skip(1)
+        .map(|after_quote| after_quote.len() - after_quote.trim_start_matches('#').len())
+        .max()
+        .unwrap_or(0);
+
+    "#".repeat(longest_run + 1)
+}
+
+/// The `mod.rs` of a new year module whose only day so far is `advent_day`.
+pub fn year_module(year: u16, advent_day: u8) -> String {
+    let registry = render_array(
+        "pub static SOLVERS: &[&dyn Solver] = &[",
+        &[format!("&day_{advent_day}::Day{advent_day}")],
+    );
+
+    format!(
+        "pub mod day_{advent_day};\n\nuse super::Solver;\n\n\
+         /// All implemented days of {year}, in ascending order.\n{registry}"
+    )
+}
+
+/// Adds the module of the day and its solver to the contents of the `mod.rs` of its year.
+pub fn register_day(mod_rs: &str, advent_day: u8) -> Result<String> {
+    register(
+        mod_rs,
+        "pub mod day_",
+        u16::from(advent_day),
+        "pub static SOLVERS: &[&dyn Solver] = &[",
+        format!("&day_{advent_day}::Day{advent_day}"),
+        |entry| {
+            entry
+                .strip_prefix("&day_")?
+                .split_once("::")?
+                .0
+                .parse()
+                .ok()
+        },
+    )
+}
+
+/// Adds the module of the year and its solvers to the contents of `days/mod.rs`.
+pub fn register_year(mod_rs: &str, year: u16) -> Result<String> {
+    register(
+        mod_rs,
+        "pub mod year_",
+        year,
+        "pub static YEARS: &[Year] = &[",
+        format!("Year::new({year}, year_{year}::SOLVERS)"),
+        |entry| {
+            entry
+                .strip_prefix("Year::new(")?
+                .split_once(',')?
+                .0
+                .parse()
+                .ok()
+        },
+    )
+}
+
+/// Declares the module `{module_prefix}{key}` and adds `entry` to the registry array starting
+/// with `registry_start`, keeping the declarations sorted by name like rustfmt does and the
+/// registry ordered by key.
+fn register(
+    source: &str,
+    module_prefix: &str,
+    key: u16,
+    registry_start: &str,
+    entry: String,
+    entry_key: impl Fn(&str) -> Option<u16>,
+) -> Result<String> {
+    let module_key = |line: &str| {
+        line.strip_prefix(module_prefix)?
+            .strip_suffix(';')?
+            .parse::<u16>()
+            .ok()
+    };
+
+    let mut lines: Vec<&str> = source.lines().collect();
+    let modules: Vec<_> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, module_key(line)?)))
+        .collect();
+    if modules.iter().any(|(_, module)| *module == key) {
+        bail!("{module_prefix}{key} is already registered!");
+    }
+
+    // rustfmt sorts the declarations by name, which puts e.g. day_10 before day_9.
+    let module_index = match modules
+        .iter()
+        .find(|(_, module)| module.to_string() > key.to_string())
+    {
+        Some((index, _)) => *index,
+        None => match modules.last() {
+            Some((index, _)) => index + 1,
+            None => bail!("Unable to find the {module_prefix}* declarations!"),
+        },
+    };
+    let module_line = format!("{module_prefix}{key};");
+    lines.insert(module_index, &module_line);
+    let source = lines.join("\n") + "\n";
+
+    let Some(start) = source.find(registry_start) else {
+        bail!("Unable to find \"{registry_start}\"!");
+    };
+    let entries_start = start + registry_start.len();
+    let Some(entries_length) = source[entries_start..].find("];") else {
+        bail!("Unable to find the end of \"{registry_start}\"!");
+    };
+    let entries_end = entries_start + entries_length + "];".len();
+
+    let mut entries = split_entries(&source[entries_start..entries_end - "];".len()])
+        .into_iter()
+        .map(str::to_owned)
+        .chain([entry])
+        .map(|entry| match entry_key(&entry) {
+            Some(key) => Ok((key, entry)),
+            None => Err(eyre!(
+                "Unable to make sense of the registry entry \"{entry}\"!"
+            )),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    entries.sort_by_key(|(key, _)| *key);
+    let entries: Vec<_> = entries.into_iter().map(|(_, entry)| entry).collect();
+
+    Ok(format!(
+        "{}{}{}",
+        &source[..start],
+        render_array(registry_start, &entries).trim_end(),
+        &source[entries_end..]
+    ))
+}
+
+/// Splits the entries of an array literal at the commas that are not nested in parentheses.
+fn split_entries(entries: &str) -> Vec<&str> {
+    let mut split = Vec::new();
+    let mut depth = 0;
+    let mut entry_start = 0;
+
+    for (index, character) in entries.char_indices() {
+        match character {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                split.push(&entries[entry_start..index]);
+                entry_start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    split.push(&entries[entry_start..]);
+
+    split
+        .into_iter()
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .collect()
+}
+
+/// Renders an array literal the way rustfmt does: on a single line if it fits, one entry per
+/// line otherwise.
+fn render_array(start: &str, entries: &[String]) -> String {
+    let single_line = format!("{start}{}];", entries.join(", "));
+    if single_line.len() <= 100 {
+        return single_line + "\n";
+    }
+
+    let lines: String = entries
+        .iter()
+        .map(|entry| format!("    {entry},\n"))
+        .collect();
+    format!("{start}\n{lines}];\n")
+}
+
+/// Writes the module of a new day into `days_dir` (the `src/days` dir of the crate) and registers
+/// its solver, along with the module of its year if it is the first day of the year.
+///
+/// Returns the path of the new module.
+pub fn create_day(
+    days_dir: &Path,
+    year: u16,
+    advent_day: u8,
+    title: &str,
+    examples: &[Example],
+) -> Result<PathBuf> {
+    let year_dir = days_dir.join(format!("year_{year}"));
+    let module_path = year_dir.join(format!("day_{advent_day}.rs"));
+    if module_path.exists() {
+        bail!("{} already exists!", module_path.display());
+    }
+
+    // A new year gets a module of its own, which in turn is registered with all years.
+    let (mod_rs_path, mod_rs) = if year_dir.exists() {
+        let mod_rs_path = year_dir.join("mod.rs");
+        let mod_rs = read_source(&mod_rs_path)?;
+        (mod_rs_path, register_day(&mod_rs, advent_day)?)
+    } else {
+        let days_mod_rs_path = days_dir.join("mod.rs");
+        let days_mod_rs = register_year(&read_source(&days_mod_rs_path)?, year)?;
+        fs::create_dir_all(&year_dir)
+            .wrap_err_with(|| format!("Unable to create dir {}!", year_dir.display()))?;
+        write_source(&days_mod_rs_path, &days_mod_rs)?;
+
+        (year_dir.join("mod.rs"), year_module(year, advent_day))
+    };
+
+    write_source(&module_path, &day_module(advent_day, title, examples))?;
+    write_source(&mod_rs_path, &mod_rs)?;
+
+    Ok(module_path)
+}
+
+fn read_source(path: &Path) -> Result<String> {
+    fs::read_to_string(path).wrap_err_with(|| format!("Unable to read {}!", path.display()))
+}
+
+fn write_source(path: &Path, source: &str) -> Result<()> {
+    fs::write(path, source).wrap_err_with(|| format!("Unable to write to {}!", path.display()))
+}
+
+/// The title of the puzzle from its `## --- Day N: Title ---` heading.
+pub fn puzzle_title(puzzle: &str) -> Option<&str> {
+    puzzle.lines().find_map(|line| {
+        let heading = line.strip_prefix("## --- Day ")?.strip_suffix(" ---")?;
+
+        Some(heading.split_once(": ")?.1)
+    })
+}
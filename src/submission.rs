@@ -0,0 +1,308 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+use color_eyre::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Answer;
+use crate::input_fetcher::Client;
+use crate::Part;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input_fetcher::ClientSettings;
+    use crate::test_support::StubServer;
+    use yare::parameterized;
+
+    #[parameterized(
+        correct = { "<main><article><p>That's the right answer! You are one gold star closer to saving your vacation.</p></article></main>", Outcome::Correct },
+        too_high = { "<article><p>That's not the right answer; your answer is too high.  If you're stuck, ...</p></article>", Outcome::TooHigh },
+        too_low = { "<article><p>That's not the right answer; your answer is too low.</p></article>", Outcome::TooLow },
+        incorrect = { "<article><p>That's not the right answer.  If you're stuck, ...</p></article>", Outcome::Incorrect },
+        wait_seconds = { "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait. <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article>", Outcome::Wait(Duration::from_secs(34)) },
+        wait_minutes = { "<article><p>You gave an answer too recently. You have 4m 2s left to wait.</p></article>", Outcome::Wait(Duration::from_secs(242)) },
+        wrong_level = { "<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>", Outcome::WrongLevel },
+    )]
+    fn parse_response_recognises_the_outcome(response: &str, expected: Outcome) {
+        // Given
+
+        // When
+        let outcome = parse_response(response);
+
+        // Then
+        assert_eq!(outcome, expected);
+    }
+
+    #[test]
+    fn submit_posts_the_answer_and_parses_the_response() {
+        // Given
+        let server = StubServer::serve(vec![StubServer::ok(
+            "<article><p>That's not the right answer; your answer is too low.</p></article>",
+        )]);
+        let client = Client::new(&server.base_url(), "abc123", ClientSettings::default()).unwrap();
+
+        // When
+        let outcome = submit(&client, 2022, 3, Part::Two, &Answer::Number(69)).unwrap();
+
+        // Then
+        assert_eq!(outcome, Outcome::TooLow);
+        let requests = server.requests();
+        assert!(requests[0].starts_with("POST /2022/day/3/answer "));
+        assert!(requests[0].ends_with("level=2&answer=69"));
+    }
+
+    #[parameterized(
+        rejected_before = { "24000", Some("24000 was rejected before") },
+        above_too_high = { "25000", Some("25000 is not lower than 24500, which is too high") },
+        below_too_low = { "23000", Some("23000 is not higher than 23500, which is too low") },
+        between_the_bounds = { "24100", None },
+        text = { "CMZ", None },
+    )]
+    fn known_wrong_rules_out_answers_by_the_hints_of_earlier_rejections(
+        answer: &str,
+        expected: Option<&str>,
+    ) {
+        // Given
+        let rejections = [
+            Rejection::new(Answer::Number(24000), &Outcome::Incorrect).unwrap(),
+            Rejection::new(Answer::Number(24500), &Outcome::TooHigh).unwrap(),
+            Rejection::new(Answer::Number(23500), &Outcome::TooLow).unwrap(),
+        ];
+
+        // When
+        let reason = known_wrong(&Answer::parse(answer), &rejections);
+
+        // Then
+        assert_eq!(reason.as_deref(), expected);
+    }
+
+    #[parameterized(
+        number = { Answer::Number(24000) },
+        text = { Answer::from("ABC DEF") },
+        multiple_lines = { Answer::from("#..#\t\n####\n") },
+    )]
+    fn rejections_survive_a_round_trip_through_a_single_line(answer: Answer) {
+        // Given
+        let rejection = Rejection::new(answer, &Outcome::TooLow).unwrap();
+
+        // When
+        let line = rejection.to_line();
+        let parsed = Rejection::parse(&line);
+
+        // Then
+        assert_eq!(line.lines().count(), 1);
+        assert_eq!(parsed, Some(rejection));
+    }
+
+    #[test]
+    fn parse_response_keeps_the_text_of_unrecognised_responses() {
+        // Given
+        let response = "<html><article><p>Something <em>new</em> happened.</p></article></html>";
+
+        // When
+        let outcome = parse_response(response);
+
+        // Then
+        assert_eq!(
+            outcome,
+            Outcome::Unknown("Something new happened.".to_owned())
+        );
+    }
+}
+
+/// What the aoc website made of a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint whether the answer was too high or too low.
+    Incorrect,
+    /// An answer was submitted too recently; retry after the given time.
+    Wait(Duration),
+    /// The part was either already solved or is not unlocked yet.
+    WrongLevel,
+    Unknown(String),
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "Correct!"),
+            Outcome::TooHigh => write!(f, "Wrong, the answer is too high."),
+            Outcome::TooLow => write!(f, "Wrong, the answer is too low."),
+            Outcome::Incorrect => write!(f, "Wrong."),
+            Outcome::Wait(duration) => write!(
+                f,
+                "An answer was submitted too recently, wait {}s before trying again.",
+                duration.as_secs()
+            ),
+            Outcome::WrongLevel => {
+                write!(f, "This part is either already solved or not unlocked yet.")
+            }
+            Outcome::Unknown(text) => write!(f, "Unrecognised response: {text}"),
+        }
+    }
+}
+
+/// An answer the website rejected, along with its hint whether the answer was too high or too
+/// low.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rejection {
+    pub answer: Answer,
+    /// Either [`Outcome::TooHigh`], [`Outcome::TooLow`] or [`Outcome::Incorrect`].
+    pub outcome: Outcome,
+}
+
+impl Rejection {
+    /// The rejection of `answer`, if `outcome` is one.
+    pub fn new(answer: Answer, outcome: &Outcome) -> Option<Self> {
+        match outcome {
+            Outcome::TooHigh | Outcome::TooLow | Outcome::Incorrect => Some(Self {
+                answer,
+                outcome: outcome.clone(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Reads a rejection as written by [`Rejection::to_line`].
+    pub fn parse(line: &str) -> Option<Self> {
+        let stored: StoredRejection = serde_json::from_str(line).ok()?;
+        let outcome = match stored.outcome.as_str() {
+            "too_high" => Outcome::TooHigh,
+            "too_low" => Outcome::TooLow,
+            "incorrect" => Outcome::Incorrect,
+            _ => return None,
+        };
+        let answer = match stored.answer.parse() {
+            Ok(number) => Answer::Number(number),
+            Err(_) => Answer::Text(stored.answer),
+        };
+
+        Self::new(answer, &outcome)
+    }
+
+    /// The rejection as a JSON object on a single line, even if the answer spans several.
+    pub fn to_line(&self) -> String {
+        let outcome = match self.outcome {
+            Outcome::TooHigh => "too_high",
+            Outcome::TooLow => "too_low",
+            _ => "incorrect",
+        };
+        let stored = StoredRejection {
+            outcome: outcome.to_owned(),
+            answer: self.answer.to_string(),
+        };
+
+        serde_json::to_string(&stored).expect("Rejections are always serializable!")
+    }
+}
+
+/// How a [`Rejection`] is written to the cache.
+#[derive(Serialize, Deserialize)]
+struct StoredRejection {
+    outcome: String,
+    answer: String,
+}
+
+/// Why `answer` is wrong for sure, judging by the answers the website rejected before.
+pub fn known_wrong(answer: &Answer, rejections: &[Rejection]) -> Option<String> {
+    rejections.iter().find_map(|rejection| {
+        let rejected = &rejection.answer;
+        if answer == rejected {
+            return Some(format!("{answer} was rejected before"));
+        }
+
+        match (&rejection.outcome, answer.compare(rejected)) {
+            (Outcome::TooHigh, Some(Ordering::Greater)) => Some(format!(
+                "{answer} is not lower than {rejected}, which is too high"
+            )),
+            (Outcome::TooLow, Some(Ordering::Less)) => Some(format!(
+                "{answer} is not higher than {rejected}, which is too low"
+            )),
+            _ => None,
+        }
+    })
+}
+
+/// Submits `answer` for the given part of the day and reports what the website made of it.
+pub fn submit(
+    client: &Client,
+    year: u16,
+    advent_day: u8,
+    part: Part,
+    answer: &Answer,
+) -> Result<Outcome> {
+    let response = client.submit(year, advent_day, part.level(), &answer.to_string())?;
+
+    Ok(parse_response(&response))
+}
+
+pub fn parse_response(response: &str) -> Outcome {
+    let text = article_text(response);
+
+    if text.contains("That's the right answer") {
+        Outcome::Correct
+    } else if text.contains("That's not the right answer") {
+        if text.contains("too high") {
+            Outcome::TooHigh
+        } else if text.contains("too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Incorrect
+        }
+    } else if text.contains("You gave an answer too recently") {
+        match parse_wait_time(&text) {
+            Some(duration) => Outcome::Wait(duration),
+            None => Outcome::Unknown(text),
+        }
+    } else if text.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        Outcome::Unknown(text)
+    }
+}
+
+/// Returns the text inside the `<article>` element of the page, without any markup.
+fn article_text(response: &str) -> String {
+    let article = match (response.find("<article"), response.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &response[start..end],
+        _ => response,
+    };
+
+    let mut text = String::new();
+    let mut inside_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => inside_tag = true,
+            '>' => inside_tag = false,
+            c if !inside_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.trim().to_owned()
+}
+
+/// Parses the "You have 1m 23s left to wait" part of a rate-limited response.
+fn parse_wait_time(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    let mut seconds = 0;
+    for component in text[start..end].split_whitespace() {
+        let (value, unit) = component.split_at(component.len() - 1);
+        let value: u64 = value.parse().ok()?;
+        seconds += match unit {
+            "h" => value * 3600,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+
+    Some(Duration::from_secs(seconds))
+}
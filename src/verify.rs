@@ -0,0 +1,184 @@
+//! Checks that the solvers still reproduce the answers the aoc website accepted, e.g. after a
+//! refactoring.
+
+use color_eyre::Result;
+
+use crate::cache::SessionCache;
+use crate::days::Year;
+use crate::input_fetcher::Client;
+use crate::{catch_panic, Part};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+    use crate::days::find_year;
+    use crate::test_support::TempDir;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn verify_reports_the_accepted_answers_that_were_not_reproduced() {
+        // Given
+        let temp_dir = TempDir::new();
+        let cache = SessionCache::in_dir(temp_dir.path().to_owned(), 2022);
+        cache
+            .write_input(1, "1000\n\n2000\n3000\n\n4000\n")
+            .unwrap();
+        cache
+            .write_answer(1, Part::One, &Answer::from(5000))
+            .unwrap();
+        cache
+            .write_answer(1, Part::Two, &Answer::from(7000))
+            .unwrap();
+        // Never contacted, as all inputs with an accepted answer are cached.
+        let client = unreachable_client();
+
+        // When
+        let verification =
+            verify(find_year(2022).unwrap(), &cache, &client, false, |_| {}).unwrap();
+
+        // Then
+        assert_eq!(verification.verified_count, 2);
+        assert_eq!(
+            verification.failures,
+            vec!["Day 1 part Two: expected 7000, got 10000"]
+        );
+    }
+
+    #[test]
+    fn verify_counts_accepted_answers_whose_input_cannot_be_loaded_as_failed_checks() {
+        // Given
+        let temp_dir = TempDir::new();
+        let cache = SessionCache::in_dir(temp_dir.path().to_owned(), 2022);
+        cache
+            .write_answer(1, Part::One, &Answer::from(5000))
+            .unwrap();
+        let client = unreachable_client();
+
+        // When
+        let verification =
+            verify(find_year(2022).unwrap(), &cache, &client, false, |_| {}).unwrap();
+
+        // Then
+        assert_eq!(verification.verified_count, 1);
+        assert_eq!(verification.failures.len(), 1);
+        assert!(verification.failures[0].starts_with("Day 1 part One: unable to load input"));
+    }
+
+    #[test]
+    fn verify_checks_the_cached_answers_of_days_whose_accepted_answers_cannot_be_fetched() {
+        // Given
+        let temp_dir = TempDir::new();
+        let cache = SessionCache::in_dir(temp_dir.path().to_owned(), 2022);
+        cache
+            .write_input(1, "1000\n\n2000\n3000\n\n4000\n")
+            .unwrap();
+        cache
+            .write_answer(1, Part::One, &Answer::from(5000))
+            .unwrap();
+        let year = Year {
+            year: 2022,
+            solvers: &find_year(2022).unwrap().solvers[..1],
+        };
+        let client = unreachable_client();
+
+        // When
+        let mut fetched_days = Vec::new();
+        let verification = verify(&year, &cache, &client, true, |advent_day| {
+            fetched_days.push(advent_day)
+        })
+        .unwrap();
+
+        // Then
+        assert_eq!(verification.verified_count, 1);
+        assert_eq!(verification.failures, Vec::<String>::new());
+        assert_eq!(fetched_days, vec![1]);
+        assert_eq!(verification.fetch_failures.len(), 1);
+        assert!(verification.fetch_failures[0]
+            .starts_with("Day 1: unable to fetch the accepted answers"));
+    }
+
+    fn unreachable_client() -> Client {
+        Client::new(
+            &"http://127.0.0.1:1".parse().unwrap(),
+            "abc123",
+            Default::default(),
+        )
+        .unwrap()
+    }
+}
+
+/// The outcome of [`verify`].
+#[derive(Debug)]
+pub struct Verification {
+    /// The number of accepted answers that were checked.
+    pub verified_count: usize,
+    /// Why each of the accepted answers that were not reproduced differs, or why its input
+    /// could not be loaded.
+    pub failures: Vec<String>,
+    /// Why the accepted answers of some days could not be fetched with `fetch_answers`.
+    pub fetch_failures: Vec<String>,
+}
+
+/// Solves all parts of `year` that have an accepted answer in the cache again and compares the
+/// solutions with the accepted answers.
+///
+/// With `fetch_answers`, the answers accepted on the aoc website are recorded first for every
+/// day that is missing some, e.g. because they were submitted in the browser. `on_fetch` is
+/// called with each of these days before its answers are fetched.
+pub fn verify(
+    year: &Year,
+    cache: &SessionCache,
+    client: &Client,
+    fetch_answers: bool,
+    mut on_fetch: impl FnMut(u8),
+) -> Result<Verification> {
+    let mut verified_count = 0;
+    let mut failures = Vec::new();
+    let mut fetch_failures = Vec::new();
+
+    for solver in year.solvers {
+        let advent_day = solver.day();
+        let mut input = None;
+
+        if fetch_answers && cache.read_answer(advent_day, Part::Two)?.is_none() {
+            on_fetch(advent_day);
+            if let Err(e) = cache.load_puzzle(advent_day, client, true) {
+                fetch_failures.push(format!(
+                    "Day {advent_day}: unable to fetch the accepted answers: {e:#}"
+                ));
+            }
+        }
+
+        for part in Part::ALL {
+            let Some(expected) = cache.read_answer(advent_day, part)? else {
+                continue;
+            };
+
+            verified_count += 1;
+            let input = match input.get_or_insert_with(|| cache.load_input(advent_day, client)) {
+                Ok(input) => input,
+                Err(e) => {
+                    failures.push(format!(
+                        "Day {advent_day} part {part:?}: unable to load input: {e}"
+                    ));
+                    continue;
+                }
+            };
+
+            match catch_panic(|| part.solve(*solver, &input.text)).and_then(|actual| actual) {
+                Ok(actual) if actual == expected => {}
+                Ok(actual) => failures.push(format!(
+                    "Day {advent_day} part {part:?}: expected {expected}, got {actual}"
+                )),
+                Err(e) => failures.push(format!("Day {advent_day} part {part:?}: {e:#}")),
+            }
+        }
+    }
+
+    Ok(Verification {
+        verified_count,
+        failures,
+        fetch_failures,
+    })
+}
@@ -0,0 +1,308 @@
+//! Notices changes to the files a solver depends on and reruns it, for the `watch` command.
+
+use color_eyre::{
+    eyre::{bail, Context},
+    Result,
+};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::Part;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+    use pretty_assertions::assert_eq;
+    use std::fs::File;
+
+    #[test]
+    fn file_watcher_reports_each_modification_once() {
+        // Given
+        let temp_dir = TempDir::new();
+        let path = temp_dir.path().join("input.txt");
+        fs::write(&path, "1000").unwrap();
+        let mut watcher = FileWatcher::new(vec![path.clone()]);
+
+        // When
+        let changed_before = watcher.changed();
+        File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(SystemTime::now() + Duration::from_secs(10))
+            .unwrap();
+        let changed_after = watcher.changed();
+        let changed_again = watcher.changed();
+
+        // Then
+        assert_eq!(
+            (changed_before, changed_after, changed_again),
+            (false, true, false)
+        );
+    }
+
+    #[test]
+    fn watch_passes_input_files_to_the_solver_as_absolute_paths() {
+        // Given
+        let input = WatchedInput::File(PathBuf::from("input.txt"));
+
+        // When
+        let watch = Watch::new(2022, 1, Part::One, input, None, Vec::new()).unwrap();
+
+        // Then
+        let input_path = std::path::absolute("input.txt").unwrap();
+        assert_eq!(
+            watch.solve_args[watch.solve_args.len() - 2..],
+            ["--input".to_owned(), input_path.display().to_string()]
+        );
+        assert_eq!(watch.paths()[1], input_path);
+    }
+
+    #[test]
+    fn watch_fails_for_stdin() {
+        // Given
+        let input = WatchedInput::File(PathBuf::from("-"));
+
+        // When
+        let result = Watch::new(2022, 1, Part::One, input, None, Vec::new());
+
+        // Then
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "Unable to watch stdin for changes!"
+        );
+    }
+
+    #[test]
+    fn diff_answers_marks_changed_lines() {
+        // Given
+        let previous = "#..#\n#..#\n####";
+        let current = "#..#\n####";
+
+        // When
+        let diff = diff_answers(previous, current);
+
+        // Then
+        assert_eq!(diff, "  #..#\n- #..#\n+ ####\n- ####\n");
+    }
+
+    #[test]
+    fn diff_answers_reports_unchanged_answers() {
+        // Given
+        let answer = "24000";
+
+        // When
+        let diff = diff_answers(answer, answer);
+
+        // Then
+        assert_eq!(diff, "  24000 (unchanged)\n");
+    }
+}
+
+/// Where the watched solver takes its input from.
+pub enum WatchedInput {
+    /// A file, which is watched as well.
+    File(PathBuf),
+    /// The example input of the solver.
+    Example,
+    /// The cache of the session.
+    Session,
+}
+
+/// Runs the tests and then the solver of a day whenever its source file or input file changes.
+///
+/// Both run in a new `cargo` process, so that they pick up the changes to the source code.
+pub struct Watch {
+    year: u16,
+    advent_day: u8,
+    solve_args: Vec<String>,
+    session_id: Option<String>,
+    watcher: FileWatcher,
+}
+
+impl Watch {
+    /// Watches the given part of the day. `remote_args` are passed on to the solver.
+    pub fn new(
+        year: u16,
+        advent_day: u8,
+        part: Part,
+        input: WatchedInput,
+        session_id: Option<String>,
+        remote_args: Vec<String>,
+    ) -> Result<Self> {
+        let mut paths = vec![manifest_dir()
+            .join("src")
+            .join("days")
+            .join(format!("year_{year}"))
+            .join(format!("day_{advent_day}.rs"))];
+        let mut solve_args = vec![
+            "run".to_owned(),
+            "--quiet".to_owned(),
+            "--".to_owned(),
+            "--year".to_owned(),
+            year.to_string(),
+        ];
+        solve_args.extend(remote_args);
+        solve_args.extend([
+            "solve".to_owned(),
+            advent_day.to_string(),
+            format!("{part:?}").to_lowercase(),
+            "--format".to_owned(),
+            "json".to_owned(),
+        ]);
+        match input {
+            WatchedInput::File(path) if path == Path::new("-") => {
+                bail!("Unable to watch stdin for changes!")
+            }
+            WatchedInput::File(path) => {
+                // The solver runs in the directory of the crate.
+                let path = std::path::absolute(&path)
+                    .wrap_err_with(|| format!("Unable to resolve {}!", path.display()))?;
+                solve_args.extend(["--input".to_owned(), path.display().to_string()]);
+                paths.push(path);
+            }
+            WatchedInput::Example => solve_args.push("--example".to_owned()),
+            WatchedInput::Session => {}
+        }
+
+        Ok(Self {
+            year,
+            advent_day,
+            solve_args,
+            session_id,
+            watcher: FileWatcher::new(paths),
+        })
+    }
+
+    /// The files whose changes trigger another run.
+    pub fn paths(&self) -> &[PathBuf] {
+        self.watcher.paths()
+    }
+
+    /// Runs the tests of the day, with their output going to stderr, and tells whether they
+    /// passed.
+    pub fn run_tests(&self) -> Result<bool> {
+        let status = process::Command::new(cargo())
+            .args([
+                "test",
+                "--quiet",
+                &format!("days::year_{}::day_{}::", self.year, self.advent_day),
+            ])
+            .current_dir(manifest_dir())
+            .stdout(io::stderr())
+            .status()
+            .wrap_err("Unable to run the tests!")?;
+
+        Ok(status.success())
+    }
+
+    /// Runs the solver and returns its answer, or `None` if it failed.
+    pub fn run_solver(&self) -> Result<Option<String>> {
+        let mut command = process::Command::new(cargo());
+        command
+            .args(&self.solve_args)
+            .current_dir(manifest_dir())
+            .stderr(process::Stdio::inherit());
+        // Passed on through the environment to keep it out of the process list.
+        if let Some(session_id) = &self.session_id {
+            command.env("AOC_SESSION_ID", session_id);
+        }
+        let output = command.output().wrap_err("Unable to run the solver!")?;
+        if !output.status.success() {
+            return Ok(None);
+        }
+
+        let answer = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .find_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+            .and_then(|report| match &report["answer"] {
+                serde_json::Value::String(text) => Some(text.clone()),
+                serde_json::Value::Number(number) => Some(number.to_string()),
+                _ => None,
+            });
+
+        Ok(answer)
+    }
+
+    /// Blocks until any of the watched files changes.
+    pub fn wait_for_changes(&mut self) {
+        while !self.watcher.changed() {
+            thread::sleep(Duration::from_millis(500));
+        }
+    }
+}
+
+fn manifest_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn cargo() -> &'static str {
+    option_env!("CARGO").unwrap_or("cargo")
+}
+
+/// Polls the modification times of a set of files.
+pub struct FileWatcher {
+    paths: Vec<PathBuf>,
+    modified: Vec<Option<SystemTime>>,
+}
+
+impl FileWatcher {
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        let modified = paths.iter().map(|path| modified(path)).collect();
+
+        Self { paths, modified }
+    }
+
+    pub fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    /// Whether any of the files was modified, created or deleted since the last call.
+    pub fn changed(&mut self) -> bool {
+        let modified: Vec<_> = self.paths.iter().map(|path| modified(path)).collect();
+        let changed = modified != self.modified;
+        self.modified = modified;
+
+        changed
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+/// Compares two answers line by line, marking removed lines with "-" and added ones with "+".
+pub fn diff_answers(previous: &str, current: &str) -> String {
+    if previous == current {
+        return format!("  {current} (unchanged)\n");
+    }
+
+    let previous_lines: Vec<_> = previous.lines().collect();
+    let current_lines: Vec<_> = current.lines().collect();
+
+    let mut diff = String::new();
+    for index in 0..previous_lines.len().max(current_lines.len()) {
+        match (previous_lines.get(index), current_lines.get(index)) {
+            (Some(previous), Some(current)) if previous == current => {
+                diff.push_str(&format!("  {current}\n"));
+            }
+            (previous, current) => {
+                if let Some(previous) = previous {
+                    diff.push_str(&format!("- {previous}\n"));
+                }
+                if let Some(current) = current {
+                    diff.push_str(&format!("+ {current}\n"));
+                }
+            }
+        }
+    }
+
+    diff
+}
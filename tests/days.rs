@@ -0,0 +1,83 @@
+use advent_of_code_2022::answer::Answer;
+use advent_of_code_2022::days::year_2022::{day_5, day_7};
+use advent_of_code_2022::days::{find_year, parse_all, YEARS};
+use advent_of_code_2022::Part;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn every_solver_with_an_example_input_solves_both_parts_of_it() {
+    // Given
+    let solvers = YEARS.iter().flat_map(|year| year.solvers);
+
+    // When
+    let failures: Vec<_> = solvers
+        .filter_map(|solver| Some((solver, solver.example_input()?)))
+        .flat_map(|(solver, input)| {
+            Part::ALL.map(|part| (solver.day(), part, part.solve(*solver, input)))
+        })
+        .filter_map(|(day, part, outcome)| {
+            outcome
+                .err()
+                .map(|e| format!("Day {day} part {part:?}: {e:#}"))
+        })
+        .collect();
+
+    // Then
+    assert_eq!(failures, Vec::<String>::new());
+}
+
+#[test]
+fn solvers_are_found_by_year_and_day() {
+    // Given
+    let year = find_year(2022).unwrap();
+
+    // When
+    let solver = year.find(7).unwrap();
+
+    // Then
+    assert_eq!(solver.title(), "No Space Left On Device");
+    assert_eq!(
+        solver.part_one(solver.example_input().unwrap()).unwrap(),
+        Answer::Number(95437)
+    );
+}
+
+#[test]
+fn day_5_input_can_be_parsed_on_its_own() {
+    // Given
+    let input = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\n";
+
+    // When
+    let puzzle_input = parse_all(day_5::parse_input, input).unwrap();
+
+    // Then
+    let stack_heights: Vec<_> = puzzle_input.stacks.iter().map(Vec::len).collect();
+    assert_eq!(stack_heights, vec![2, 3, 1]);
+    assert_eq!(
+        puzzle_input.instructions,
+        vec![day_5::Instruction {
+            count: 1,
+            from: 2,
+            to: 1,
+        }]
+    );
+}
+
+#[test]
+fn day_7_tree_can_be_walked_on_its_own() {
+    // Given
+    let input = "$ cd /\n$ ls\ndir a\n100 b.txt\n$ cd a\n$ ls\n20 c\n";
+
+    // When
+    let tree = day_7::parse_whole_tree(input).unwrap();
+
+    // Then
+    let mut directories = Vec::new();
+    tree.walk_apply(&mut |directory| {
+        directories.push((directory.name().to_owned(), directory.size()))
+    });
+    assert_eq!(
+        directories,
+        vec![("/".to_owned(), 120), ("a".to_owned(), 20)]
+    );
+}